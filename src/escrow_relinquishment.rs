@@ -0,0 +1,390 @@
+use crate::rns_v1_badge_lockers::{
+    account_from_proof, assert_irreversibility_confirmed, V1BadgeType,
+};
+use scrypto::prelude::*;
+
+// What turned an escrow into a permanent lock
+#[derive(ScryptoSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EscrowFinalizationTrigger {
+    // The V2-launch attestation was presented
+    Attestation,
+    // The deadline passed without an attestation
+    Deadline,
+}
+
+// Badges an account holds in escrow
+#[derive(ScryptoSbor, Clone, Debug, Default, PartialEq, Eq)]
+pub struct EscrowedBadges {
+    pub admin_badges: Decimal,
+    pub upgrade_badges: Decimal,
+}
+
+// Status of an EscrowRelinquishment
+#[derive(ScryptoSbor, Debug)]
+pub struct EscrowStatus {
+    pub admin_badges_held: Decimal,
+    pub upgrade_badges_held: Decimal,
+    pub admin_badge_resource: ResourceAddress,
+    pub upgrade_badge_resource: ResourceAddress,
+    // The non-fungible whose proof attests the V2 launch
+    pub attestation: NonFungibleGlobalId,
+    pub deadline: Instant,
+    // Whether the badges are permanently locked, either by finalization or because the deadline
+    // has passed
+    pub is_permanent: bool,
+    // How and when the escrow was finalized (None until `confirm_v2_launch` or
+    // `finalize_after_deadline` is called)
+    pub finalized_by: Option<EscrowFinalizationTrigger>,
+    pub finalized_at: Option<Instant>,
+}
+
+// Event emitted when badges are placed in escrow
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct BadgesEscrowedEvent {
+    pub depositor: ComponentAddress,
+    pub badge_type: V1BadgeType,
+    pub amount: Decimal,
+    // Amount of this badge type the depositor holds in escrow, including this deposit
+    pub depositor_total: Decimal,
+    pub timestamp: Instant,
+}
+
+// Event emitted when escrowed badges are returned to their depositor
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct EscrowRefundedEvent {
+    pub depositor: ComponentAddress,
+    pub badge_type: V1BadgeType,
+    pub amount: Decimal,
+    pub timestamp: Instant,
+}
+
+// Event emitted when the escrow becomes a permanent lock
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct EscrowFinalizedEvent {
+    pub trigger: EscrowFinalizationTrigger,
+    pub admin_badges_locked: Decimal,
+    pub upgrade_badges_locked: Decimal,
+    pub timestamp: Instant,
+}
+
+#[blueprint]
+#[events(BadgesEscrowedEvent, EscrowRefundedEvent, EscrowFinalizedEvent)]
+mod escrow_relinquishment {
+    use super::*;
+
+    enable_function_auth! {
+        instantiate => rule!(allow_all);
+    }
+
+    enable_method_auth! {
+        methods {
+            escrow => PUBLIC;
+            refund => PUBLIC;
+            confirm_v2_launch => PUBLIC;
+            finalize_after_deadline => PUBLIC;
+            is_permanent => PUBLIC;
+            get_escrowed_by => PUBLIC;
+            get_escrow_status => PUBLIC;
+        }
+    }
+
+    // Escrow Relinquishment
+    //
+    // A cautious variant of V1AuthRelinquishment: deposited V1 badges stay refundable to their
+    // depositor until the V2 launch is attested on ledger, by presenting a proof of a specific
+    // non-fungible, or until a deadline passes. Either event turns the escrow into a permanent
+    // lock with no withdrawal path. Like V1AuthRelinquishment, the escrow has no owner and no
+    // roles.
+
+    pub struct EscrowRelinquishment {
+        v1_admin_badge_resource: ResourceAddress,
+        v1_upgrade_badge_resource: ResourceAddress,
+
+        // Vaults holding escrowed badges, permanently locked once the escrow is final
+        v1_admin_badges_vault: Vault,
+        v1_upgrade_badges_vault: Vault,
+
+        // The non-fungible whose proof attests the V2 launch
+        attestation: NonFungibleGlobalId,
+
+        // Time from which the escrow is permanent even without an attestation
+        deadline: Instant,
+
+        // Badges each depositor holds in escrow
+        escrowed: KeyValueStore<ComponentAddress, EscrowedBadges>,
+
+        // How and when the escrow was finalized
+        finalized_by: Option<EscrowFinalizationTrigger>,
+        finalized_at: Option<Instant>,
+    }
+
+    impl EscrowRelinquishment {
+        // Instantiates an escrow for the given V1 badge resources.
+        //
+        // # Arguments
+        // * `admin_resource` - Resource address of V1 admin badges
+        // * `upgrade_resource` - Resource address of V1 upgrade badges
+        // * `attestation` - The non-fungible whose proof attests the V2 launch
+        // * `deadline` - Time from which the escrow is permanent even without an attestation
+        //
+        // # Returns
+        // The globalized component
+        //
+        // # Panics
+        // * If both badge resource addresses are the same
+        // * If the deadline is not in the future
+        pub fn instantiate(
+            admin_resource: ResourceAddress,
+            upgrade_resource: ResourceAddress,
+            attestation: NonFungibleGlobalId,
+            deadline: Instant,
+        ) -> Global<EscrowRelinquishment> {
+            assert_ne!(
+                admin_resource, upgrade_resource,
+                "Admin and upgrade badge resources must be different"
+            );
+            assert!(
+                deadline > Clock::current_time_rounded_to_seconds(),
+                "Escrow deadline must be in the future"
+            );
+
+            Self {
+                v1_admin_badge_resource: admin_resource,
+                v1_upgrade_badge_resource: upgrade_resource,
+                v1_admin_badges_vault: Vault::new(admin_resource),
+                v1_upgrade_badges_vault: Vault::new(upgrade_resource),
+                attestation,
+                deadline,
+                escrowed: KeyValueStore::new(),
+                finalized_by: None,
+                finalized_at: None,
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .metadata(metadata! {
+                init {
+                    "name" => "RNS V1 Badge Escrow", locked;
+                    "description" => "Holds RNS V1 badges refundable until the V2 launch is attested, then locks them permanently.", locked;
+                    "tags" => ["rns", "v1", "deprecation", "escrow"], locked;
+                }
+            })
+            .globalize()
+        }
+
+        // Places V1 badges in escrow. They can be refunded to the depositor until the escrow
+        // becomes permanent, after which they are locked forever.
+        //
+        // # Arguments
+        // * `badges` - Bucket containing V1 admin or upgrade badges
+        // * `account_proof` - Proof of the depositing account's owner badge
+        // * `confirmation` - Must equal IRREVERSIBILITY_CONFIRMATION
+        //
+        // # Panics
+        // * If the confirmation does not equal IRREVERSIBILITY_CONFIRMATION
+        // * If the escrow is already permanent
+        // * If the bucket contains neither badge resource or is empty
+        // * If the account proof is not of an account owner badge
+        pub fn escrow(&mut self, badges: Bucket, account_proof: Proof, confirmation: String) {
+            assert_irreversibility_confirmed(&confirmation);
+            assert!(!self.is_permanent(), "The escrow is permanent");
+            let depositor = account_from_proof(account_proof, "Depositor");
+            let badge_type = self.badge_type_of_bucket(&badges);
+            let amount = badges.amount();
+            assert!(
+                amount.is_positive(),
+                "Cannot escrow an empty bucket of V1 {} badges",
+                badge_type.label()
+            );
+
+            match badge_type {
+                V1BadgeType::Admin => self.v1_admin_badges_vault.put(badges),
+                V1BadgeType::Upgrade => self.v1_upgrade_badges_vault.put(badges),
+            }
+
+            let mut escrowed = self.get_escrowed_by(depositor);
+            let depositor_total = match badge_type {
+                V1BadgeType::Admin => {
+                    escrowed.admin_badges += amount;
+                    escrowed.admin_badges
+                }
+                V1BadgeType::Upgrade => {
+                    escrowed.upgrade_badges += amount;
+                    escrowed.upgrade_badges
+                }
+            };
+            self.escrowed.insert(depositor, escrowed);
+
+            Runtime::emit_event(BadgesEscrowedEvent {
+                depositor,
+                badge_type,
+                amount,
+                depositor_total,
+                timestamp: Clock::current_time_rounded_to_seconds(),
+            });
+        }
+
+        // Returns all badges of one type the depositor holds in escrow.
+        //
+        // # Arguments
+        // * `badge_type` - The badge type to refund
+        // * `account_proof` - Proof of the depositing account's owner badge
+        //
+        // # Returns
+        // The refunded badges
+        //
+        // # Panics
+        // * If the escrow is permanent
+        // * If the account proof is not of an account owner badge
+        // * If the account holds no badges of that type in escrow
+        pub fn refund(&mut self, badge_type: V1BadgeType, account_proof: Proof) -> Bucket {
+            assert!(
+                !self.is_permanent(),
+                "The escrow is permanent; badges can no longer be refunded"
+            );
+            let depositor = account_from_proof(account_proof, "Depositor");
+
+            let mut escrowed = self.get_escrowed_by(depositor);
+            let amount = match badge_type {
+                V1BadgeType::Admin => std::mem::take(&mut escrowed.admin_badges),
+                V1BadgeType::Upgrade => std::mem::take(&mut escrowed.upgrade_badges),
+            };
+            assert!(
+                amount.is_positive(),
+                "{:?} holds no V1 {} badges in escrow",
+                depositor,
+                badge_type.label()
+            );
+            self.escrowed.insert(depositor, escrowed);
+
+            Runtime::emit_event(EscrowRefundedEvent {
+                depositor,
+                badge_type,
+                amount,
+                timestamp: Clock::current_time_rounded_to_seconds(),
+            });
+
+            match badge_type {
+                V1BadgeType::Admin => self.v1_admin_badges_vault.take(amount),
+                V1BadgeType::Upgrade => self.v1_upgrade_badges_vault.take(amount),
+            }
+        }
+
+        // Attests the V2 launch, turning the escrow into a permanent lock.
+        //
+        // # Arguments
+        // * `attestation_proof` - Proof of the configured attestation non-fungible
+        //
+        // # Panics
+        // * If the proof is not of the attestation non-fungible
+        // * If the escrow is already permanent
+        pub fn confirm_v2_launch(&mut self, attestation_proof: Proof) {
+            let attestation_proof = attestation_proof.check_with_message(
+                self.attestation.resource_address(),
+                "Proof is not of the attestation resource",
+            );
+            assert!(
+                attestation_proof
+                    .as_non_fungible()
+                    .non_fungible_local_ids()
+                    .contains(self.attestation.local_id()),
+                "Proof is not of the attestation non-fungible {}",
+                self.attestation.local_id()
+            );
+            assert!(!self.is_permanent(), "The escrow is already permanent");
+
+            self.finalize(EscrowFinalizationTrigger::Attestation);
+        }
+
+        // Records that the deadline has passed without an attestation.
+        //
+        // The escrow is permanent from the deadline on regardless; anyone can call this to
+        // record the finalization and emit `EscrowFinalizedEvent`.
+        //
+        // # Panics
+        // * If the deadline has not passed
+        // * If the escrow was already finalized
+        pub fn finalize_after_deadline(&mut self) {
+            assert!(
+                self.has_deadline_passed(),
+                "The escrow deadline {:?} has not passed",
+                self.deadline
+            );
+            assert!(
+                self.finalized_by.is_none(),
+                "The escrow is already finalized"
+            );
+
+            self.finalize(EscrowFinalizationTrigger::Deadline);
+        }
+
+        // Returns true if escrowed badges are permanently locked, either because the escrow was
+        // finalized or because the deadline has passed.
+        pub fn is_permanent(&self) -> bool {
+            self.finalized_by.is_some() || self.has_deadline_passed()
+        }
+
+        // Returns the badges an account holds in escrow.
+        //
+        // # Arguments
+        // * `account` - The depositing account
+        //
+        // # Returns
+        // `EscrowedBadges`; zero amounts for accounts that never deposited
+        pub fn get_escrowed_by(&self, account: ComponentAddress) -> EscrowedBadges {
+            self.escrowed
+                .get(&account)
+                .map(|escrowed| escrowed.clone())
+                .unwrap_or_default()
+        }
+
+        // Returns the status of the escrow.
+        //
+        // # Returns
+        // `EscrowStatus` with the badges held, the attestation, the deadline and finalization
+        pub fn get_escrow_status(&self) -> EscrowStatus {
+            EscrowStatus {
+                admin_badges_held: self.v1_admin_badges_vault.amount(),
+                upgrade_badges_held: self.v1_upgrade_badges_vault.amount(),
+                admin_badge_resource: self.v1_admin_badge_resource,
+                upgrade_badge_resource: self.v1_upgrade_badge_resource,
+                attestation: self.attestation.clone(),
+                deadline: self.deadline,
+                is_permanent: self.is_permanent(),
+                finalized_by: self.finalized_by,
+                finalized_at: self.finalized_at,
+            }
+        }
+
+        fn finalize(&mut self, trigger: EscrowFinalizationTrigger) {
+            let timestamp = Clock::current_time_rounded_to_seconds();
+            self.finalized_by = Some(trigger);
+            self.finalized_at = Some(timestamp);
+
+            Runtime::emit_event(EscrowFinalizedEvent {
+                trigger,
+                admin_badges_locked: self.v1_admin_badges_vault.amount(),
+                upgrade_badges_locked: self.v1_upgrade_badges_vault.amount(),
+                timestamp,
+            });
+        }
+
+        fn has_deadline_passed(&self) -> bool {
+            Clock::current_time_rounded_to_seconds() >= self.deadline
+        }
+
+        // Badge type of the bucket's resource; panics if it is neither badge resource
+        fn badge_type_of_bucket(&self, badges: &Bucket) -> V1BadgeType {
+            let resource = badges.resource_address();
+            if resource == self.v1_admin_badge_resource {
+                V1BadgeType::Admin
+            } else if resource == self.v1_upgrade_badge_resource {
+                V1BadgeType::Upgrade
+            } else {
+                panic!(
+                    "Invalid V1 badge resource. Expected {:?} or {:?}, received {:?}",
+                    self.v1_admin_badge_resource, self.v1_upgrade_badge_resource, resource
+                )
+            }
+        }
+    }
+}
@@ -1 +1,7 @@
+pub mod escrow_relinquishment;
+pub mod locker_factory;
+pub mod locker_registry;
+pub mod multi_resource_locker;
+pub mod pledge_registry;
 pub mod rns_v1_badge_lockers;
+pub mod v1_badge_burner;
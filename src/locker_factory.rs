@@ -0,0 +1,161 @@
+use crate::rns_v1_badge_lockers::rns_v1_badge_lockers::*;
+use crate::rns_v1_badge_lockers::{V1LockerConfig, MAX_PAGE_SIZE};
+use scrypto::prelude::*;
+
+// A locker created by a LockerFactory
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct CreatedLocker {
+    pub component_address: ComponentAddress,
+    pub admin_badge_resource: ResourceAddress,
+    pub upgrade_badge_resource: ResourceAddress,
+    pub created_at: Instant,
+}
+
+// Event emitted when a LockerFactory creates a locker
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct LockerCreatedEvent {
+    // Index of the locker in the factory (assigned sequentially from 0)
+    pub index: u64,
+    pub component_address: ComponentAddress,
+    pub admin_badge_resource: ResourceAddress,
+    pub upgrade_badge_resource: ResourceAddress,
+    pub timestamp: Instant,
+}
+
+#[blueprint]
+#[events(LockerCreatedEvent)]
+mod locker_factory {
+    use super::*;
+
+    enable_function_auth! {
+        instantiate => rule!(allow_all);
+    }
+
+    enable_method_auth! {
+        methods {
+            create_locker => PUBLIC;
+            get_locker => PUBLIC;
+            get_lockers => PUBLIC;
+            get_locker_count => PUBLIC;
+        }
+    }
+
+    // Locker Factory
+    //
+    // Creates V1AuthRelinquishment components and keeps an on-ledger record of every locker it
+    // created, so official instances can be discovered and reused by third parties. The factory
+    // has no owner and no roles; anyone can create a locker.
+
+    pub struct LockerFactory {
+        // Lockers created by this factory, keyed by index (assigned sequentially from 0)
+        lockers: KeyValueStore<u64, CreatedLocker>,
+
+        // Number of lockers created
+        locker_count: u64,
+    }
+
+    impl LockerFactory {
+        // Instantiates a locker factory.
+        //
+        // # Returns
+        // The globalized component
+        pub fn instantiate() -> Global<LockerFactory> {
+            Self {
+                lockers: KeyValueStore::new(),
+                locker_count: 0,
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .metadata(metadata! {
+                init {
+                    "name" => "RNS V1 Locker Factory", locked;
+                    "description" => "Creates and registers RNS V1 Auth Relinquishment lockers.", locked;
+                    "tags" => ["rns", "v1", "deprecation", "factory"], locked;
+                }
+            })
+            .globalize()
+        }
+
+        // Instantiates a V1AuthRelinquishment locker and registers it with the factory.
+        //
+        // # Arguments
+        // * `admin_resource` - Resource address of V1 admin badges the locker accepts
+        // * `upgrade_resource` - Resource address of V1 upgrade badges the locker accepts
+        // * `config` - Wallet display metadata and other instantiation-time settings
+        //
+        // # Returns
+        // The new locker component
+        //
+        // # Panics
+        // * On any condition under which `V1AuthRelinquishment::instantiate` panics
+        pub fn create_locker(
+            &mut self,
+            admin_resource: ResourceAddress,
+            upgrade_resource: ResourceAddress,
+            config: V1LockerConfig,
+        ) -> Global<V1AuthRelinquishment> {
+            let locker = Blueprint::<V1AuthRelinquishment>::instantiate(
+                admin_resource,
+                upgrade_resource,
+                config,
+            );
+
+            let index = self.locker_count;
+            let created_at = Clock::current_time_rounded_to_seconds();
+            self.lockers.insert(
+                index,
+                CreatedLocker {
+                    component_address: locker.address(),
+                    admin_badge_resource: admin_resource,
+                    upgrade_badge_resource: upgrade_resource,
+                    created_at,
+                },
+            );
+            self.locker_count += 1;
+
+            Runtime::emit_event(LockerCreatedEvent {
+                index,
+                component_address: locker.address(),
+                admin_badge_resource: admin_resource,
+                upgrade_badge_resource: upgrade_resource,
+                timestamp: created_at,
+            });
+
+            locker
+        }
+
+        // Returns a locker created by this factory.
+        //
+        // # Arguments
+        // * `index` - Index of the locker (the first locker is 0)
+        //
+        // # Returns
+        // The `CreatedLocker`, or `None` if no locker with that index exists yet
+        pub fn get_locker(&self, index: u64) -> Option<CreatedLocker> {
+            self.lockers.get(&index).map(|locker| locker.clone())
+        }
+
+        // Returns lockers created by this factory in creation order.
+        //
+        // # Arguments
+        // * `start` - Index of the first locker to return
+        // * `limit` - Maximum number of lockers to return (capped at MAX_PAGE_SIZE)
+        //
+        // # Returns
+        // Up to `limit` lockers starting at `start`; empty once past the last locker
+        pub fn get_lockers(&self, start: u64, limit: u32) -> Vec<CreatedLocker> {
+            let end = start
+                .saturating_add(limit.min(MAX_PAGE_SIZE) as u64)
+                .min(self.locker_count);
+
+            (start..end)
+                .filter_map(|index| self.get_locker(index))
+                .collect()
+        }
+
+        // Returns the number of lockers created by this factory.
+        pub fn get_locker_count(&self) -> u64 {
+            self.locker_count
+        }
+    }
+}
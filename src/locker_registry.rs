@@ -0,0 +1,232 @@
+use crate::rns_v1_badge_lockers::rns_v1_badge_lockers::*;
+use crate::rns_v1_badge_lockers::{account_from_proof, MAX_PAGE_SIZE};
+use scrypto::prelude::*;
+
+// A locker registered with a LockerRegistry
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct RegisteredLocker {
+    pub component_address: ComponentAddress,
+    // Account that registered the locker, which is not necessarily the account that created it
+    pub registrant: ComponentAddress,
+    pub registered_at: Instant,
+}
+
+// Lock totals summed across a page of registered lockers
+#[derive(ScryptoSbor, Debug)]
+pub struct CombinedLockStatus {
+    pub admin_badge_resource: ResourceAddress,
+    pub upgrade_badge_resource: ResourceAddress,
+    // Number of lockers registered in total
+    pub locker_count: u64,
+    // Number of lockers whose amounts are summed below
+    pub lockers_counted: u64,
+    pub admin_badges_locked: Decimal,
+    pub upgrade_badges_locked: Decimal,
+}
+
+// Event emitted when a locker is registered
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct LockerRegisteredEvent {
+    // Index of the locker in the registry (assigned sequentially from 0)
+    pub index: u64,
+    pub component_address: ComponentAddress,
+    pub registrant: ComponentAddress,
+    pub timestamp: Instant,
+}
+
+#[blueprint]
+#[events(LockerRegisteredEvent)]
+mod locker_registry {
+    use super::*;
+
+    enable_function_auth! {
+        instantiate => rule!(allow_all);
+    }
+
+    enable_method_auth! {
+        methods {
+            register_locker => PUBLIC;
+            is_registered => PUBLIC;
+            get_all_lockers => PUBLIC;
+            get_locker_count => PUBLIC;
+            get_combined_status => PUBLIC;
+        }
+    }
+
+    // Locker Registry
+    //
+    // Tracks V1AuthRelinquishment components for one pair of V1 badge resources, wherever they
+    // were created, together with the account that registered them, and reports lock totals
+    // across them. Lockers over any other resources are rejected, so the combined figures only
+    // ever count the configured badges. The registry has no owner and no roles; any account may
+    // register a locker, once.
+
+    pub struct LockerRegistry {
+        // Badge resources every registered locker must accept
+        v1_admin_badge_resource: ResourceAddress,
+        v1_upgrade_badge_resource: ResourceAddress,
+
+        // Registered lockers, keyed by index (assigned sequentially from 0)
+        lockers: KeyValueStore<u64, RegisteredLocker>,
+
+        // Index of each registered locker, keyed by component address
+        locker_indices: KeyValueStore<ComponentAddress, u64>,
+
+        // Number of registered lockers
+        locker_count: u64,
+    }
+
+    impl LockerRegistry {
+        // Instantiates a locker registry for a pair of V1 badge resources.
+        //
+        // # Arguments
+        // * `admin_resource` - Resource address of the V1 admin badges registered lockers accept
+        // * `upgrade_resource` - Resource address of the V1 upgrade badges registered lockers
+        //   accept
+        //
+        // # Returns
+        // The globalized component
+        pub fn instantiate(
+            admin_resource: ResourceAddress,
+            upgrade_resource: ResourceAddress,
+        ) -> Global<LockerRegistry> {
+            Self {
+                v1_admin_badge_resource: admin_resource,
+                v1_upgrade_badge_resource: upgrade_resource,
+                lockers: KeyValueStore::new(),
+                locker_indices: KeyValueStore::new(),
+                locker_count: 0,
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .metadata(metadata! {
+                init {
+                    "name" => "RNS V1 Locker Registry", locked;
+                    "description" => "Registry of RNS V1 Auth Relinquishment lockers.", locked;
+                    "tags" => ["rns", "v1", "deprecation", "registry"], locked;
+                }
+            })
+            .globalize()
+        }
+
+        // Registers a locker. The engine only accepts `locker` if it is a V1AuthRelinquishment
+        // component.
+        //
+        // # Arguments
+        // * `locker` - The locker to register
+        // * `account_proof` - Proof of the owner badge of the registering account, recorded as
+        //   the locker's registrant
+        //
+        // # Returns
+        // Index of the locker in the registry
+        //
+        // # Panics
+        // * If the locker is already registered
+        // * If the locker accepts other badge resources than the registry
+        // * If `account_proof` is not of an account owner badge
+        pub fn register_locker(
+            &mut self,
+            locker: Global<V1AuthRelinquishment>,
+            account_proof: Proof,
+        ) -> u64 {
+            let component_address = locker.address();
+            assert!(
+                self.locker_indices.get(&component_address).is_none(),
+                "Locker is already registered"
+            );
+            let status = locker.get_lock_status();
+            assert!(
+                status.admin_badge_resource == self.v1_admin_badge_resource
+                    && status.upgrade_badge_resource == self.v1_upgrade_badge_resource,
+                "Locker accepts {:?} and {:?}, expected {:?} and {:?}",
+                status.admin_badge_resource,
+                status.upgrade_badge_resource,
+                self.v1_admin_badge_resource,
+                self.v1_upgrade_badge_resource
+            );
+            let registrant = account_from_proof(account_proof, "Registrant");
+
+            let index = self.locker_count;
+            let registered_at = Clock::current_time_rounded_to_seconds();
+            self.lockers.insert(
+                index,
+                RegisteredLocker {
+                    component_address,
+                    registrant,
+                    registered_at,
+                },
+            );
+            self.locker_indices.insert(component_address, index);
+            self.locker_count += 1;
+
+            Runtime::emit_event(LockerRegisteredEvent {
+                index,
+                component_address,
+                registrant,
+                timestamp: registered_at,
+            });
+
+            index
+        }
+
+        // Returns true if the locker is registered.
+        pub fn is_registered(&self, component_address: ComponentAddress) -> bool {
+            self.locker_indices.get(&component_address).is_some()
+        }
+
+        // Returns registered lockers in registration order; page through all of them by
+        // advancing `start`.
+        //
+        // # Arguments
+        // * `start` - Index of the first locker to return
+        // * `limit` - Maximum number of lockers to return (capped at MAX_PAGE_SIZE)
+        //
+        // # Returns
+        // Up to `limit` lockers starting at `start`; empty once past the last locker
+        pub fn get_all_lockers(&self, start: u64, limit: u32) -> Vec<RegisteredLocker> {
+            let end = start
+                .saturating_add(limit.min(MAX_PAGE_SIZE) as u64)
+                .min(self.locker_count);
+
+            (start..end)
+                .filter_map(|index| self.lockers.get(&index).map(|locker| locker.clone()))
+                .collect()
+        }
+
+        // Returns the number of registered lockers.
+        pub fn get_locker_count(&self) -> u64 {
+            self.locker_count
+        }
+
+        // Returns lock totals summed across a page of registered lockers; sum the pages for the
+        // combined totals. Intended for preview, as it reads the status of each locker.
+        //
+        // # Arguments
+        // * `start` - Index of the first locker to count
+        // * `limit` - Maximum number of lockers to count (capped at MAX_PAGE_SIZE)
+        //
+        // # Returns
+        // `CombinedLockStatus` with the badge resources, the number of lockers registered and
+        // counted, and the amounts locked in the counted lockers
+        pub fn get_combined_status(&self, start: u64, limit: u32) -> CombinedLockStatus {
+            let lockers = self.get_all_lockers(start, limit);
+            let mut combined = CombinedLockStatus {
+                admin_badge_resource: self.v1_admin_badge_resource,
+                upgrade_badge_resource: self.v1_upgrade_badge_resource,
+                locker_count: self.locker_count,
+                lockers_counted: lockers.len() as u64,
+                admin_badges_locked: Decimal::ZERO,
+                upgrade_badges_locked: Decimal::ZERO,
+            };
+
+            for locker in lockers {
+                let status = Global::<V1AuthRelinquishment>::from(locker.component_address)
+                    .get_lock_status();
+                combined.admin_badges_locked += status.admin_badges_locked;
+                combined.upgrade_badges_locked += status.upgrade_badges_locked;
+            }
+
+            combined
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use crate::rns_v1_badge_lockers::assert_irreversibility_confirmed;
+use scrypto::prelude::*;
+
+// Maximum number of resources a MultiResourceLocker can accept, so `get_status` stays bounded
+pub const MAX_LOCKED_RESOURCES: usize = 100;
+
+// Locked balance of one of the resources accepted by a MultiResourceLocker
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct LockedResourceStatus {
+    pub resource_address: ResourceAddress,
+    pub amount_locked: Decimal,
+    pub lock_count: u64,
+    pub last_locked_at: Option<Instant>,
+}
+
+// Event emitted when one of the accepted resources is locked
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ResourceLockedEvent {
+    pub resource_address: ResourceAddress,
+    pub amount_locked: Decimal,
+    pub total_locked_now: Decimal,
+    pub timestamp: Instant,
+    pub epoch: Epoch,
+    // Sequence number of the lock, shared across all accepted resources
+    pub sequence: u64,
+}
+
+#[blueprint]
+#[events(ResourceLockedEvent)]
+mod multi_resource_locker {
+    use super::*;
+
+    enable_function_auth! {
+        instantiate => rule!(allow_all);
+    }
+
+    enable_method_auth! {
+        methods {
+            lock => PUBLIC;
+            amount_locked_of => PUBLIC;
+            get_locked_resources => PUBLIC;
+            get_status => PUBLIC;
+        }
+    }
+
+    // Multi Resource Locker
+    //
+    // A generic variant of V1AuthRelinquishment for any project deprecating old badges: it
+    // permanently locks any of the resources given at instantiation, with one vault per resource.
+    // Like V1AuthRelinquishment, it has no owner, no roles and no withdrawal path.
+
+    pub struct MultiResourceLocker {
+        // Resources that can be locked, in instantiation order
+        resources: Vec<ResourceAddress>,
+
+        // Vaults holding the permanently locked resources, created at instantiation
+        vaults: KeyValueStore<ResourceAddress, Vault>,
+
+        // Lock statistics of each resource
+        statuses: KeyValueStore<ResourceAddress, LockedResourceStatus>,
+
+        // Number of locks so far, which is also the sequence number of the next lock
+        lock_count: u64,
+    }
+
+    impl MultiResourceLocker {
+        // Instantiates a locker for the given resources.
+        //
+        // # Arguments
+        // * `resources` - Resources that can be locked (at most MAX_LOCKED_RESOURCES)
+        // * `name` - Name shown for the component in wallets and explorers (locked)
+        // * `description` - Description shown for the component (locked)
+        //
+        // # Returns
+        // The globalized component
+        //
+        // # Panics
+        // * If no resources are given, more than MAX_LOCKED_RESOURCES are, or one is listed twice
+        pub fn instantiate(
+            resources: Vec<ResourceAddress>,
+            name: String,
+            description: String,
+        ) -> Global<MultiResourceLocker> {
+            let unique_resources: IndexSet<ResourceAddress> = resources.iter().copied().collect();
+            assert!(
+                !resources.is_empty()
+                    && resources.len() <= MAX_LOCKED_RESOURCES
+                    && unique_resources.len() == resources.len(),
+                "Between 1 and {} distinct resources are required",
+                MAX_LOCKED_RESOURCES
+            );
+
+            let vaults = KeyValueStore::new();
+            let statuses = KeyValueStore::new();
+            for resource_address in resources.iter() {
+                vaults.insert(*resource_address, Vault::new(*resource_address));
+                statuses.insert(
+                    *resource_address,
+                    LockedResourceStatus {
+                        resource_address: *resource_address,
+                        amount_locked: Decimal::ZERO,
+                        lock_count: 0,
+                        last_locked_at: None,
+                    },
+                );
+            }
+
+            Self {
+                resources,
+                vaults,
+                statuses,
+                lock_count: 0,
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .metadata(metadata! {
+                init {
+                    "name" => name, locked;
+                    "description" => description, locked;
+                    "tags" => ["deprecation", "lock"], locked;
+                }
+            })
+            .globalize()
+        }
+
+        // Permanently locks a bucket of one of the accepted resources.
+        //
+        // # Arguments
+        // * `resources` - Bucket containing one of the accepted resources
+        // * `confirmation` - Must equal IRREVERSIBILITY_CONFIRMATION
+        //
+        // # Panics
+        // * If the confirmation does not equal IRREVERSIBILITY_CONFIRMATION
+        // * If the resource is not accepted by this locker
+        // * If the bucket is empty
+        pub fn lock(&mut self, resources: Bucket, confirmation: String) {
+            assert_irreversibility_confirmed(&confirmation);
+            let resource_address = resources.resource_address();
+            let amount_locked = resources.amount();
+            assert!(
+                amount_locked.is_positive(),
+                "Cannot lock an empty bucket of {:?}",
+                resource_address
+            );
+
+            let total_locked_now = {
+                let mut vault = self.vaults.get_mut(&resource_address).unwrap_or_else(|| {
+                    panic!("{:?} is not accepted by this locker", resource_address)
+                });
+                vault.put(resources);
+                vault.amount()
+            };
+
+            let timestamp = Clock::current_time_rounded_to_seconds();
+            {
+                let mut status = self
+                    .statuses
+                    .get_mut(&resource_address)
+                    .expect("Status should exist for every accepted resource");
+                status.amount_locked = total_locked_now;
+                status.lock_count += 1;
+                status.last_locked_at = Some(timestamp);
+            }
+
+            let sequence = self.lock_count;
+            self.lock_count += 1;
+            Runtime::emit_event(ResourceLockedEvent {
+                resource_address,
+                amount_locked,
+                total_locked_now,
+                timestamp,
+                epoch: Runtime::current_epoch(),
+                sequence,
+            });
+        }
+
+        // Returns the locked balance of a resource.
+        //
+        // # Arguments
+        // * `resource` - The resource to query
+        //
+        // # Returns
+        // The vault balance; zero for resources this locker does not accept
+        pub fn amount_locked_of(&self, resource: ResourceAddress) -> Decimal {
+            self.vaults
+                .get(&resource)
+                .map(|vault| vault.amount())
+                .unwrap_or(Decimal::ZERO)
+        }
+
+        // Returns the resources this locker accepts.
+        //
+        // # Returns
+        // The resources in instantiation order
+        pub fn get_locked_resources(&self) -> Vec<ResourceAddress> {
+            self.resources.clone()
+        }
+
+        // Returns the lock status of every accepted resource.
+        //
+        // # Returns
+        // One `LockedResourceStatus` per resource, in instantiation order
+        pub fn get_status(&self) -> Vec<LockedResourceStatus> {
+            self.resources
+                .iter()
+                .map(|resource| {
+                    self.statuses
+                        .get(resource)
+                        .expect("Status should exist for every accepted resource")
+                        .clone()
+                })
+                .collect()
+        }
+    }
+}
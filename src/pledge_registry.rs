@@ -0,0 +1,338 @@
+use crate::rns_v1_badge_lockers::rns_v1_badge_lockers::*;
+use crate::rns_v1_badge_lockers::{account_from_proof, V1BadgeType, MAX_PAGE_SIZE};
+use scrypto::prelude::*;
+
+// A non-binding pledge to lock V1 badges
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct Pledge {
+    pub pledger: ComponentAddress,
+    pub admin_badges: Decimal,
+    pub upgrade_badges: Decimal,
+    // When the pledge was last registered or updated
+    pub pledged_at: Instant,
+    // When `confirm_fulfillment` found the pledge fulfilled
+    pub fulfilled_at: Option<Instant>,
+    // When the pledge was withdrawn; withdrawn pledges are left out of the report
+    pub withdrawn_at: Option<Instant>,
+}
+
+// Pledged amounts compared with the amounts pledgers have locked, over a page of pledgers
+#[derive(ScryptoSbor, Debug)]
+pub struct PledgeReport {
+    // Number of accounts that have ever pledged
+    pub pledger_count: u64,
+    // Number of pledges in the page that were not withdrawn
+    pub pledge_count: u64,
+    pub admin_badges_pledged: Decimal,
+    pub upgrade_badges_pledged: Decimal,
+    // Amounts locked by pledgers, each capped at the pledged amount
+    pub admin_badges_fulfilled: Decimal,
+    pub upgrade_badges_fulfilled: Decimal,
+    // Number of pledges whose pledgers have locked at least the pledged amounts
+    pub fulfilled_pledge_count: u64,
+}
+
+// Event emitted when a pledge is registered or updated
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PledgeRegisteredEvent {
+    pub pledger: ComponentAddress,
+    pub admin_badges: Decimal,
+    pub upgrade_badges: Decimal,
+    pub timestamp: Instant,
+}
+
+// Event emitted when a pledge is withdrawn
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PledgeWithdrawnEvent {
+    pub pledger: ComponentAddress,
+    pub timestamp: Instant,
+}
+
+// Event emitted once per pledge, when `confirm_fulfillment` finds it fulfilled
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PledgeFulfilledEvent {
+    pub pledger: ComponentAddress,
+    pub admin_badges: Decimal,
+    pub upgrade_badges: Decimal,
+    pub timestamp: Instant,
+}
+
+#[blueprint]
+#[events(PledgeRegisteredEvent, PledgeWithdrawnEvent, PledgeFulfilledEvent)]
+mod pledge_registry {
+    use super::*;
+
+    enable_function_auth! {
+        instantiate => rule!(allow_all);
+    }
+
+    enable_method_auth! {
+        methods {
+            pledge => PUBLIC;
+            withdraw_pledge => PUBLIC;
+            confirm_fulfillment => PUBLIC;
+            get_pledge => PUBLIC;
+            get_locker => PUBLIC;
+            pledged_vs_fulfilled => PUBLIC;
+        }
+    }
+
+    // Pledge Registry
+    //
+    // Lets V1 badge holders commit now and lock later: an account registers a non-binding pledge
+    // of the badges it intends to lock, backed by a proof that it holds them but without
+    // transferring any, and fulfills it by locking through the locker's attributed lock
+    // methods. Pledges are compared with the locker's contributor totals, so locks are never
+    // routed through this component. The registry has no owner and no roles.
+
+    pub struct PledgeRegistry {
+        // Locker whose attributed locks fulfill pledges
+        locker: Global<V1AuthRelinquishment>,
+
+        // Badge resources of the locker, whose proofs back pledges
+        v1_admin_badge_resource: ResourceAddress,
+        v1_upgrade_badge_resource: ResourceAddress,
+
+        // Pledges, keyed by pledging account
+        pledges: KeyValueStore<ComponentAddress, Pledge>,
+
+        // Accounts that have pledged, keyed by index (assigned sequentially from 0)
+        pledgers: KeyValueStore<u64, ComponentAddress>,
+
+        // Number of accounts that have pledged
+        pledger_count: u64,
+    }
+
+    impl PledgeRegistry {
+        // Instantiates a pledge registry for a locker.
+        //
+        // # Arguments
+        // * `locker` - The V1AuthRelinquishment component whose attributed locks fulfill pledges
+        //
+        // # Returns
+        // The globalized component
+        pub fn instantiate(locker: Global<V1AuthRelinquishment>) -> Global<PledgeRegistry> {
+            let status = locker.get_lock_status();
+
+            Self {
+                locker,
+                v1_admin_badge_resource: status.admin_badge_resource,
+                v1_upgrade_badge_resource: status.upgrade_badge_resource,
+                pledges: KeyValueStore::new(),
+                pledgers: KeyValueStore::new(),
+                pledger_count: 0,
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .metadata(metadata! {
+                init {
+                    "name" => "RNS V1 Relinquishment Pledges", locked;
+                    "description" => "Non-binding pledges to lock RNS V1 badges.", locked;
+                    "tags" => ["rns", "v1", "deprecation", "pledge"], locked;
+                }
+            })
+            .globalize()
+        }
+
+        // Registers a pledge of the badges proven, replacing the account's previous pledge if any.
+        // No badges are transferred; the pledge is fulfilled by locking them with a proof of the
+        // same account.
+        //
+        // # Arguments
+        // * `admin_badges_proof` - Optional proof of the V1 admin badges the account intends to
+        //   lock; the pledged amount is the amount proven
+        // * `upgrade_badges_proof` - Optional proof of the V1 upgrade badges the account intends
+        //   to lock
+        // * `account_proof` - Proof of the pledging account's owner badge
+        //
+        // # Panics
+        // * If a badge proof is not of the locker's badge resource of that type
+        // * If no badges are proven
+        // * If the account proof is not of an account owner badge
+        pub fn pledge(
+            &mut self,
+            admin_badges_proof: Option<Proof>,
+            upgrade_badges_proof: Option<Proof>,
+            account_proof: Proof,
+        ) {
+            let admin_badges = self.proven_amount(V1BadgeType::Admin, admin_badges_proof);
+            let upgrade_badges = self.proven_amount(V1BadgeType::Upgrade, upgrade_badges_proof);
+            assert!(
+                admin_badges.is_positive() || upgrade_badges.is_positive(),
+                "At least one badge must be proven"
+            );
+            let pledger = account_from_proof(account_proof, "Pledger");
+
+            if self.pledges.get(&pledger).is_none() {
+                self.pledgers.insert(self.pledger_count, pledger);
+                self.pledger_count += 1;
+            }
+
+            let timestamp = Clock::current_time_rounded_to_seconds();
+            self.pledges.insert(
+                pledger,
+                Pledge {
+                    pledger,
+                    admin_badges,
+                    upgrade_badges,
+                    pledged_at: timestamp,
+                    fulfilled_at: None,
+                    withdrawn_at: None,
+                },
+            );
+
+            Runtime::emit_event(PledgeRegisteredEvent {
+                pledger,
+                admin_badges,
+                upgrade_badges,
+                timestamp,
+            });
+        }
+
+        // Withdraws the account's pledge.
+        //
+        // # Arguments
+        // * `account_proof` - Proof of the pledging account's owner badge
+        //
+        // # Panics
+        // * If the account proof is not of an account owner badge
+        // * If the account has no pledge, or it was already withdrawn
+        pub fn withdraw_pledge(&mut self, account_proof: Proof) {
+            let pledger = account_from_proof(account_proof, "Pledger");
+            let timestamp = Clock::current_time_rounded_to_seconds();
+            {
+                let mut pledge = self
+                    .pledges
+                    .get_mut(&pledger)
+                    .filter(|pledge| pledge.withdrawn_at.is_none())
+                    .unwrap_or_else(|| panic!("{:?} has no pledge", pledger));
+                pledge.withdrawn_at = Some(timestamp);
+            }
+
+            Runtime::emit_event(PledgeWithdrawnEvent { pledger, timestamp });
+        }
+
+        // Records that a pledge is fulfilled, once the pledger has locked at least the pledged
+        // amounts through attributed locks. Anyone can call this.
+        //
+        // # Arguments
+        // * `pledger` - The pledging account
+        //
+        // # Returns
+        // `true` if the pledge is fulfilled (emitting `PledgeFulfilledEvent` the first time)
+        //
+        // # Panics
+        // * If the account has no pledge, or it was withdrawn
+        pub fn confirm_fulfillment(&mut self, pledger: ComponentAddress) -> bool {
+            let pledge = self
+                .get_pledge(pledger)
+                .filter(|pledge| pledge.withdrawn_at.is_none())
+                .unwrap_or_else(|| panic!("{:?} has no pledge", pledger));
+            if pledge.fulfilled_at.is_some() {
+                return true;
+            }
+
+            let (admin_locked, upgrade_locked) = self.locker.get_contributor_totals(pledger);
+            if admin_locked < pledge.admin_badges || upgrade_locked < pledge.upgrade_badges {
+                return false;
+            }
+
+            let timestamp = Clock::current_time_rounded_to_seconds();
+            {
+                let mut pledge = self.pledges.get_mut(&pledger).expect("Pledge should exist");
+                pledge.fulfilled_at = Some(timestamp);
+            }
+            Runtime::emit_event(PledgeFulfilledEvent {
+                pledger,
+                admin_badges: pledge.admin_badges,
+                upgrade_badges: pledge.upgrade_badges,
+                timestamp,
+            });
+
+            true
+        }
+
+        // Returns an account's pledge.
+        //
+        // # Arguments
+        // * `pledger` - The pledging account
+        //
+        // # Returns
+        // The `Pledge` (including a withdrawn one), or `None` if the account never pledged
+        pub fn get_pledge(&self, pledger: ComponentAddress) -> Option<Pledge> {
+            self.pledges.get(&pledger).map(|pledge| pledge.clone())
+        }
+
+        // Returns the locker whose attributed locks fulfill pledges.
+        pub fn get_locker(&self) -> Global<V1AuthRelinquishment> {
+            self.locker
+        }
+
+        // Compares the amounts pledged with the amounts pledgers have locked, for a page of
+        // pledgers in pledging order; sum the pages for the full report. Intended for preview, as
+        // it reads each pledger's totals from the locker.
+        //
+        // Locks made before pledging count towards fulfillment, and each pledger's locked amounts
+        // are capped at the pledged amounts.
+        //
+        // # Arguments
+        // * `start` - Index of the first pledger to include
+        // * `limit` - Maximum number of pledgers to include (capped at MAX_PAGE_SIZE)
+        //
+        // # Returns
+        // `PledgeReport` summed over the page's pledges that were not withdrawn
+        pub fn pledged_vs_fulfilled(&self, start: u64, limit: u32) -> PledgeReport {
+            let end = start
+                .saturating_add(limit.min(MAX_PAGE_SIZE) as u64)
+                .min(self.pledger_count);
+            let mut report = PledgeReport {
+                pledger_count: self.pledger_count,
+                pledge_count: 0,
+                admin_badges_pledged: Decimal::ZERO,
+                upgrade_badges_pledged: Decimal::ZERO,
+                admin_badges_fulfilled: Decimal::ZERO,
+                upgrade_badges_fulfilled: Decimal::ZERO,
+                fulfilled_pledge_count: 0,
+            };
+
+            for index in start..end {
+                let pledger = *self.pledgers.get(&index).expect("Pledger should exist");
+                let pledge = self.get_pledge(pledger).expect("Pledge should exist");
+                if pledge.withdrawn_at.is_some() {
+                    continue;
+                }
+
+                let (admin_locked, upgrade_locked) = self.locker.get_contributor_totals(pledger);
+                report.pledge_count += 1;
+                report.admin_badges_pledged += pledge.admin_badges;
+                report.upgrade_badges_pledged += pledge.upgrade_badges;
+                report.admin_badges_fulfilled += admin_locked.min(pledge.admin_badges);
+                report.upgrade_badges_fulfilled += upgrade_locked.min(pledge.upgrade_badges);
+                if admin_locked >= pledge.admin_badges && upgrade_locked >= pledge.upgrade_badges {
+                    report.fulfilled_pledge_count += 1;
+                }
+            }
+
+            report
+        }
+
+        // Amount proven of the badge type's resource, or zero without a proof
+        fn proven_amount(&self, badge_type: V1BadgeType, badges_proof: Option<Proof>) -> Decimal {
+            let resource = match badge_type {
+                V1BadgeType::Admin => self.v1_admin_badge_resource,
+                V1BadgeType::Upgrade => self.v1_upgrade_badge_resource,
+            };
+
+            badges_proof
+                .map(|proof| {
+                    proof
+                        .check_with_message(
+                            resource,
+                            &format!("Proof is not of V1 {} badges", badge_type.label()),
+                        )
+                        .amount()
+                })
+                .unwrap_or(Decimal::ZERO)
+        }
+    }
+}
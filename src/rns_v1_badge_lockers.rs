@@ -1,5 +1,410 @@
 use scrypto::prelude::*;
 
+// Maximum number of official notices that can ever be posted to the notice board
+pub const MAX_NOTICES: u64 = 100;
+
+// Maximum length (in bytes) of a notice title
+pub const MAX_NOTICE_TITLE_LENGTH: usize = 100;
+
+// Maximum number of entries returned by a single paginated query
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+// Number of top contributors kept in the on-ledger leaderboard
+pub const MAX_LEADERBOARD_SIZE: usize = 100;
+
+// Maximum length (in bytes) of a memo attached to a lock
+pub const MAX_MEMO_LENGTH: usize = 280;
+
+// Confirmation that lock methods require, so copy-pasted manifests cannot lock by accident
+pub const IRREVERSIBILITY_CONFIRMATION: &str = "I UNDERSTAND THIS IS IRREVERSIBLE";
+
+// Instantiation-time configuration of the locker component
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
+pub struct V1LockerConfig {
+    // dApp definition account the component belongs to (`dapp_definition` metadata)
+    pub dapp_definition: GlobalAddress,
+    // Websites claimed for wallet verification (`claimed_websites` metadata)
+    pub claimed_websites: Vec<UncheckedOrigin>,
+    // Related entities claimed for wallet verification (`claimed_entities` metadata)
+    pub claimed_entities: Vec<GlobalAddress>,
+    // Icon shown for the component in wallets and explorers (`icon_url` metadata)
+    pub icon_url: UncheckedUrl,
+    // Page with more information about the relinquishment (`info_url` metadata)
+    pub info_url: UncheckedUrl,
+    // Reject fractional badge amounts so locked totals stay whole numbers
+    pub whole_units_only: bool,
+    // RNS team accounts whose proof-attributed locks are tracked separately from the community's
+    pub team_accounts: Vec<ComponentAddress>,
+    // Cumulative attributed amounts (admin + upgrade) at which commemorative tiers are reached
+    pub tier_thresholds: V1TierThresholds,
+    // Wallet display metadata of the receipt NFT resource
+    pub receipt_branding: V1ResourceBranding,
+    // Whether receipts can never be withdrawn (soulbound) or are freely transferable
+    pub soulbound_receipts: bool,
+    // Wallet display metadata of the commemorative NFT resource
+    pub commemorative_branding: V1ResourceBranding,
+    // When the relinquishment campaign counts as complete, if it has a defined end
+    pub completion_condition: Option<V1CompletionCondition>,
+    // Cumulative locked amounts of admin badges celebrated with a MilestoneReachedEvent
+    // (strictly ascending; percentages of the fixed V1 supply can be given as amounts)
+    pub admin_milestones: Vec<Decimal>,
+    // Cumulative locked amounts of upgrade badges celebrated with a MilestoneReachedEvent
+    pub upgrade_milestones: Vec<Decimal>,
+    // Cumulative locked amount of admin badges targeted by the campaign, if any
+    pub admin_target: Option<Decimal>,
+    // Cumulative locked amount of upgrade badges targeted by the campaign, if any
+    pub upgrade_target: Option<Decimal>,
+    // Epoch at which the campaign window opens, if any; locks are refused before it
+    pub campaign_start_epoch: Option<Epoch>,
+    // Epoch at which the campaign window closes, if any (see `late_lock_policy`)
+    pub campaign_end_epoch: Option<Epoch>,
+    // Minutes during which badges staged with `stage_lock` can be reclaimed by their depositor
+    pub staging_window_minutes: i64,
+    // Additional deprecated V1 resources (e.g. reserved-domain badges) that can be retired with
+    // `lock_other_v1_resource`
+    pub other_v1_resources: Vec<ResourceAddress>,
+    // Increment of cumulative locked admin badges at which a CheckpointEvent is emitted, if any
+    pub admin_checkpoint_interval: Option<Decimal>,
+    // Increment of cumulative locked upgrade badges at which a CheckpointEvent is emitted, if any
+    pub upgrade_checkpoint_interval: Option<Decimal>,
+    // Whether locks are refused or accepted as late once the campaign window has closed
+    pub late_lock_policy: V1LateLockPolicy,
+    // The V2-launch attestation non-fungible whose proof completes the campaign with
+    // `finalize_with_attestation`, if any
+    pub attestation: Option<NonFungibleGlobalId>,
+    // Positive minimum amount of V1 admin badges a `post_notice` proof must hold, so that
+    // holders of a dust fraction of a badge cannot fill the notice board
+    pub min_notice_proof_amount: Decimal,
+}
+
+// Returns the highest multiple of `interval` that `total` has reached
+fn last_checkpoint(total: Decimal, interval: Decimal) -> Decimal {
+    (total / interval)
+        .checked_round(0, RoundingMode::ToZero)
+        .expect("Checkpoint count should not overflow")
+        * interval
+}
+
+// Returns true if the amounts are positive and strictly ascending
+fn is_strictly_ascending(amounts: &[Decimal]) -> bool {
+    amounts.iter().all(|amount| amount.is_positive())
+        && amounts.windows(2).all(|pair| pair[0] < pair[1])
+}
+
+// Locked totals at which the relinquishment campaign is complete, and where the artifact
+// commemorating completion is sent
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
+pub struct V1CompletionCondition {
+    pub admin_badges_target: Decimal,
+    pub upgrade_badges_target: Decimal,
+    pub archive_account: ComponentAddress,
+}
+
+// How locks are handled once the campaign window has closed
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum V1LateLockPolicy {
+    // Refuse further locks
+    Refuse,
+    // Keep accepting locks, flagged as late in lock records and events
+    AcceptLate,
+}
+
+// Wallet display metadata of a resource created by the locker (locked at creation)
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
+pub struct V1ResourceBranding {
+    pub name: String,
+    pub description: String,
+    pub icon_url: UncheckedUrl,
+    pub info_url: UncheckedUrl,
+}
+
+// Cumulative contribution thresholds of the commemorative tiers (must be strictly ascending)
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
+pub struct V1TierThresholds {
+    pub bronze: Decimal,
+    pub silver: Decimal,
+    pub gold: Decimal,
+}
+
+impl V1TierThresholds {
+    // Returns the tier reached by a cumulative contribution, if any
+    fn tier_of(&self, total_locked: Decimal) -> Option<V1CommemorativeTier> {
+        if total_locked >= self.gold {
+            Some(V1CommemorativeTier::Gold)
+        } else if total_locked >= self.silver {
+            Some(V1CommemorativeTier::Silver)
+        } else if total_locked >= self.bronze {
+            Some(V1CommemorativeTier::Bronze)
+        } else {
+            None
+        }
+    }
+}
+
+// Commemorative tiers, from lowest to highest
+#[derive(ScryptoSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum V1CommemorativeTier {
+    Bronze,
+    Silver,
+    Gold,
+}
+
+// The kinds of V1 badge accepted by the locker
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum V1BadgeType {
+    Admin,
+    Upgrade,
+}
+
+impl V1BadgeType {
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            V1BadgeType::Admin => "admin",
+            V1BadgeType::Upgrade => "upgrade",
+        }
+    }
+}
+
+// Panics unless the caller confirmed that locking is irreversible
+pub(crate) fn assert_irreversibility_confirmed(confirmation: &str) {
+    assert_eq!(
+        confirmation, IRREVERSIBILITY_CONFIRMATION,
+        "Locking is irreversible: pass \"{}\" to confirm",
+        IRREVERSIBILITY_CONFIRMATION
+    );
+}
+
+// Resolves the account whose owner badge is presented in `account_proof`; `role` names the
+// proof in the panic message
+pub(crate) fn account_from_proof(account_proof: Proof, role: &str) -> ComponentAddress {
+    let account_proof = account_proof.check_with_message(
+        ACCOUNT_OWNER_BADGE,
+        &format!("{} proof must be of an account owner badge", role),
+    );
+
+    match account_proof.as_non_fungible().non_fungible_local_id() {
+        NonFungibleLocalId::Bytes(bytes) => ComponentAddress::try_from(bytes.value())
+            .expect("Account owner badge id does not encode an account address"),
+        _ => panic!("Account owner badge id does not encode an account address"),
+    }
+}
+
+// Reads the recall and freeze exposure of a badge resource from its roles. A role counts as
+// live unless both it and its updater are `deny_all`, as an updatable role can be enabled later.
+fn resource_risks(resource: ResourceAddress) -> V1ResourceRisks {
+    let manager = ResourceManager::from(resource);
+    let is_live = |role: &str| {
+        [role.to_string(), format!("{}_updater", role)]
+            .iter()
+            .any(|name| {
+                manager
+                    .get_role(name)
+                    .is_some_and(|rule| rule != AccessRule::DenyAll)
+            })
+    };
+
+    V1ResourceRisks {
+        recallable: is_live("recaller"),
+        freezable: is_live("freezer"),
+    }
+}
+
+// Returns true if the amount has no fractional part
+fn is_whole_amount(amount: Decimal) -> bool {
+    amount.checked_floor() == Some(amount)
+}
+
+// Formats an amount with thousands separators, e.g. 12345.5 as "12,345.5"
+fn format_amount(amount: Decimal) -> String {
+    let amount = amount.to_string();
+    let (whole, fraction) = match amount.split_once('.') {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (amount.as_str(), None),
+    };
+
+    let mut formatted = String::new();
+    for (i, digit) in whole.chars().enumerate() {
+        if i > 0 && (whole.len() - i) % 3 == 0 {
+            formatted.push(',');
+        }
+        formatted.push(digit);
+    }
+    if let Some(fraction) = fraction {
+        formatted.push('.');
+        formatted.push_str(fraction);
+    }
+
+    formatted
+}
+
+// JSON value of an optional decimal: a string (to preserve precision) or null
+fn json_decimal(amount: Option<Decimal>) -> String {
+    amount.map_or("null".to_string(), |amount| format!("\"{}\"", amount))
+}
+
+// JSON value of an optional instant: seconds since the unix epoch or null
+fn json_instant(instant: Option<Instant>) -> String {
+    instant.map_or("null".to_string(), |instant| {
+        instant.seconds_since_unix_epoch.to_string()
+    })
+}
+
+// Local id of an account's commemorative NFT: the account's address bytes
+fn commemorative_nft_id(account: ComponentAddress) -> NonFungibleLocalId {
+    NonFungibleLocalId::bytes(account.as_node_id().0.to_vec())
+        .expect("Account address bytes should form a valid local id")
+}
+
+// Local id of a contributor's receipt for a badge type: the account's address bytes followed
+// by 0 for admin or 1 for upgrade badges
+fn receipt_nft_id(account: ComponentAddress, badge_type: V1BadgeType) -> NonFungibleLocalId {
+    let mut id = account.as_node_id().0.to_vec();
+    id.push(match badge_type {
+        V1BadgeType::Admin => 0,
+        V1BadgeType::Upgrade => 1,
+    });
+    NonFungibleLocalId::bytes(id).expect("Receipt id bytes should form a valid local id")
+}
+
+// Lock statistics for a single attributed contributor
+#[derive(ScryptoSbor, Clone, Debug, Default)]
+pub struct ContributorStats {
+    pub admin_badges_locked: Decimal,
+    pub upgrade_badges_locked: Decimal,
+    pub lock_count: u64,
+    pub first_locked_at: Option<Instant>,
+    pub last_locked_at: Option<Instant>,
+}
+
+// A single lock, stored on-ledger under its sequence number
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct LockRecord {
+    pub seq: u64,
+    pub badge_type: V1BadgeType,
+    pub amount: Decimal,
+    pub timestamp: Instant,
+    pub epoch: Epoch,
+    pub contributor: Option<ComponentAddress>,
+    // Public statement attached with `lock_admin_badges_with_memo` / `lock_upgrade_badges_with_memo`
+    pub memo: Option<String>,
+    // Whether the lock was accepted after the campaign window closed
+    pub late: bool,
+}
+
+// A point on the progress curve, recorded when a lock reaches a milestone or checkpoint
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct ProgressPoint {
+    pub badge_type: V1BadgeType,
+    pub timestamp: Instant,
+    // Cumulative amount of the badge type locked after the lock
+    pub total_locked: Decimal,
+    // Sequence number of the lock
+    pub sequence: u64,
+}
+
+// Data of the receipt held by a contributor for each badge type they locked with an
+// account proof. Repeat contributions update the existing receipt rather than minting another.
+#[derive(ScryptoSbor, NonFungibleData, Clone, Debug)]
+pub struct V1RelinquishmentReceipt {
+    pub contributor: ComponentAddress,
+    pub badge_type: V1BadgeType,
+    pub first_contribution: Instant,
+    // Cumulative amount of this badge type attributed to the contributor
+    #[mutable]
+    pub total_locked: Decimal,
+    // Timestamp of the latest proof-attributed lock
+    #[mutable]
+    pub last_contribution: Instant,
+    // Sequence number, epoch and amount of the latest proof-attributed lock, so the receipt
+    // alone evidences it (see `get_lock_record`)
+    #[mutable]
+    pub last_seq: u64,
+    #[mutable]
+    pub last_epoch: Epoch,
+    #[mutable]
+    pub last_amount: Decimal,
+}
+
+// Data of the commemorative NFT any attributed contributor can claim once
+#[derive(ScryptoSbor, NonFungibleData, Clone, Debug)]
+pub struct V1CommemorativeNft {
+    pub contributor: ComponentAddress,
+    pub first_locked_at: Instant,
+    pub claimed_at: Instant,
+    // Upgraded by the component whenever the contributor's cumulative total crosses a threshold
+    #[mutable]
+    pub tier: Option<V1CommemorativeTier>,
+}
+
+// Data of the one-off "V1 Fully Relinquished" artifact minted when the campaign completes
+#[derive(ScryptoSbor, NonFungibleData, Clone, Debug)]
+pub struct V1FullyRelinquishedArtifact {
+    pub completed_at: Instant,
+    // Sequence number of the lock that completed the campaign (None if it was completed by
+    // attestation)
+    pub completing_seq: Option<u64>,
+    pub admin_badges_locked: Decimal,
+    pub upgrade_badges_locked: Decimal,
+    pub unique_contributors: u64,
+}
+
+// Data of the immutable final report minted with `mint_final_report` once the campaign completes
+#[derive(ScryptoSbor, NonFungibleData, Clone, Debug)]
+pub struct V1FinalReport {
+    pub completed_at: Instant,
+    pub admin_badges_locked: Decimal,
+    pub upgrade_badges_locked: Decimal,
+    pub unique_contributors: u64,
+    pub lock_count: u64,
+    // Contribution digest (see `get_contribution_digest`) at the time of the report
+    pub history_root: Hash,
+    pub reported_at: Instant,
+}
+
+// Lock totals aggregated over a single epoch
+#[derive(ScryptoSbor, Clone, Debug, Default)]
+pub struct EpochStats {
+    pub admin_badges_locked: Decimal,
+    pub upgrade_badges_locked: Decimal,
+    pub lock_count: u64,
+}
+
+// Powers a badge resource's own roles hold over the locker's vault, which the locker cannot
+// prevent
+#[derive(ScryptoSbor, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct V1ResourceRisks {
+    // Whether the recaller role (or its updater) is not `deny_all`, so locked badges can be
+    // recalled out of the vault
+    pub recallable: bool,
+    // Whether the freezer role (or its updater) is not `deny_all`, so the vault can be frozen
+    pub freezable: bool,
+}
+
+// Running lock statistics of a single badge type, maintained incrementally on every lock
+#[derive(ScryptoSbor, Clone, Debug, Default)]
+pub struct BadgeLockStats {
+    pub lock_count: u64,
+    // Sum of all locked amounts (unlike the vault balance, unaffected by resource-level recalls)
+    pub total_locked: Decimal,
+    pub largest_lock: Decimal,
+    pub average_lock: Decimal,
+    pub first_locked_at: Option<Instant>,
+    pub last_locked_at: Option<Instant>,
+}
+
+impl BadgeLockStats {
+    fn record(&mut self, amount: Decimal, timestamp: Instant) {
+        self.lock_count += 1;
+        self.total_locked += amount;
+        if amount > self.largest_lock {
+            self.largest_lock = amount;
+        }
+        self.average_lock = self.total_locked / Decimal::from(self.lock_count);
+        self.first_locked_at.get_or_insert(timestamp);
+        self.last_locked_at = Some(timestamp);
+    }
+}
+
 // Status of V1 badge locking
 #[derive(ScryptoSbor, Debug)]
 pub struct V1LockStatus {
@@ -9,32 +414,475 @@ pub struct V1LockStatus {
     pub upgrade_badge_resource: ResourceAddress,
 }
 
+// Extended status of V1 badge locking (V1LockStatus is kept unchanged for existing integrations)
+#[derive(ScryptoSbor, Debug)]
+pub struct V1LockStatusV2 {
+    pub admin_badges_locked: Decimal,
+    pub upgrade_badges_locked: Decimal,
+    pub admin_badge_resource: ResourceAddress,
+    pub upgrade_badge_resource: ResourceAddress,
+    // Total supply of each resource at instantiation, the fixed denominator of all progress
+    // figures (None if the supply is not tracked)
+    pub admin_supply_at_instantiation: Option<Decimal>,
+    pub upgrade_supply_at_instantiation: Option<Decimal>,
+    // Whether `check_supply_integrity` has ever found a supply differing from the snapshot
+    pub supply_anomaly_detected: bool,
+    // Recall and freeze exposure of each badge resource, read from its roles at instantiation
+    pub admin_badge_risks: V1ResourceRisks,
+    pub upgrade_badge_risks: V1ResourceRisks,
+    // Percentage of each resource's supply snapshot locked (None if the snapshot is missing or
+    // zero)
+    pub admin_percent_locked: Option<Decimal>,
+    pub upgrade_percent_locked: Option<Decimal>,
+    // Number of locks so far, which is also the sequence number of the next lock
+    pub lock_count: u64,
+    pub unique_contributors: u64,
+    pub team_admin_badges_locked: Decimal,
+    pub team_upgrade_badges_locked: Decimal,
+    pub community_admin_badges_locked: Decimal,
+    pub community_upgrade_badges_locked: Decimal,
+    pub admin_lock_stats: BadgeLockStats,
+    pub upgrade_lock_stats: BadgeLockStats,
+    // When the completion condition was met; receipts are no longer issued afterwards
+    pub completed_at: Option<Instant>,
+    // Resource of the artifact minted at completion
+    pub fully_relinquished_artifact: Option<ResourceAddress>,
+    // Resource of the final report minted after completion, if it has been minted
+    pub final_report: Option<ResourceAddress>,
+    // When the entire supply of both badge resources was first held in the vaults
+    pub entire_supply_locked_at: Option<Instant>,
+    // Whether all configured lock targets have been met (see `targets_met`)
+    pub targets_met: bool,
+    // The V2-launch attestation non-fungible that can complete the campaign, if any
+    pub attestation: Option<NonFungibleGlobalId>,
+    // Epochs at which the campaign window opens and closes, if any
+    pub campaign_start_epoch: Option<Epoch>,
+    pub campaign_end_epoch: Option<Epoch>,
+    // Epochs until the campaign window closes (None without an end epoch)
+    pub epochs_remaining: Option<u64>,
+    // Whether the campaign window is open, so locks are accepted (always true without start and
+    // end epochs)
+    pub campaign_window_open: bool,
+}
+
+// Outcome of a hypothetical lock, as returned by `preview_lock`
+#[derive(ScryptoSbor, Debug)]
+pub struct V1LockPreview {
+    // Extended status as it would be after the lock
+    pub status: V1LockStatusV2,
+    // Milestones of the badge type the lock would reach, ascending
+    pub milestones_reached: Vec<Decimal>,
+    // Lowest milestone of the badge type still unreached after the lock, if any
+    pub next_milestone: Option<Decimal>,
+}
+
+// Data of a locked non-fungible badge, decoded without knowing its schema
+#[derive(ScryptoSbor)]
+#[sbor(transparent)]
+struct RawNonFungibleData(ScryptoValue);
+
+impl NonFungibleData for RawNonFungibleData {
+    const MUTABLE_FIELDS: &'static [&'static str] = &[];
+}
+
+// Internal addresses of the vaults owned by the component
+#[derive(ScryptoSbor, Debug)]
+pub struct V1VaultIds {
+    pub admin_vault: InternalAddress,
+    pub upgrade_vault: InternalAddress,
+    // Vaults of the additional V1 resources, in configuration order
+    pub other_v1_vaults: Vec<(ResourceAddress, InternalAddress)>,
+    // Number of locks staged so far, each with its own staging vault
+    pub staged_lock_count: u64,
+    // `(staged_lock_id, vault)` for the requested page of staged locks
+    pub staged_vaults: Vec<(u64, InternalAddress)>,
+}
+
+// Provenance of the component and the resources it was configured with
+#[derive(ScryptoSbor, Debug)]
+pub struct V1ComponentInfo {
+    pub package_address: PackageAddress,
+    pub blueprint_name: String,
+    pub component_address: ComponentAddress,
+    pub instantiated_epoch: Epoch,
+    pub instantiated_at: Instant,
+    pub admin_badge_resource: ResourceAddress,
+    pub upgrade_badge_resource: ResourceAddress,
+    pub receipt_resource: ResourceAddress,
+    pub relinquished_resource: ResourceAddress,
+    pub commemorative_resource: ResourceAddress,
+    pub receipt_locker: ComponentAddress,
+}
+
+// Event emitted when a locker component is instantiated
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct V1LockerInstantiatedEvent {
+    pub component_address: ComponentAddress,
+    pub admin_badge_resource: ResourceAddress,
+    pub upgrade_badge_resource: ResourceAddress,
+    pub timestamp: Instant,
+}
+
 // Event emitted when V1 admin badges are locked
 #[derive(ScryptoSbor, ScryptoEvent)]
 pub struct V1AdminBadgesLockedEvent {
+    pub resource_address: ResourceAddress,
+    pub badge_type: V1BadgeType,
     pub badges_locked: Decimal,
     pub total_locked_now: Decimal,
+    pub contributor: Option<ComponentAddress>,
     pub timestamp: Instant,
+    pub epoch: Epoch,
+    // Sequence number of the lock, shared across both badge types (see `get_lock_record`)
+    pub sequence: u64,
+    // Public statement attached to the lock, if any
+    pub memo: Option<String>,
+    // Whether the lock was accepted after the campaign window closed
+    pub late: bool,
 }
 
 // Event emitted when V1 upgrade badges are locked
 #[derive(ScryptoSbor, ScryptoEvent)]
 pub struct V1UpgradeBadgeLockedEvent {
+    pub resource_address: ResourceAddress,
+    pub badge_type: V1BadgeType,
     pub badges_locked: Decimal,
     pub total_locked_now: Decimal,
+    pub contributor: Option<ComponentAddress>,
+    pub timestamp: Instant,
+    pub epoch: Epoch,
+    // Sequence number of the lock, shared across both badge types (see `get_lock_record`)
+    pub sequence: u64,
+    // Public statement attached to the lock, if any
+    pub memo: Option<String>,
+    // Whether the lock was accepted after the campaign window closed
+    pub late: bool,
+}
+
+// Event emitted instead of the per-type lock events when both badge types are locked in one
+// `lock_all` call
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct V1BadgesLockedEvent {
+    pub admin_badges_locked: Decimal,
+    pub upgrade_badges_locked: Decimal,
+    pub admin_total_locked_now: Decimal,
+    pub upgrade_total_locked_now: Decimal,
+    pub timestamp: Instant,
+    pub epoch: Epoch,
+    // Sequence numbers of the locks, admin first
+    pub sequences: Vec<u64>,
+    // Whether the locks were accepted after the campaign window closed
+    pub late: bool,
+}
+
+// Event emitted when an additional V1 resource is locked with `lock_other_v1_resource`
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct OtherV1ResourceLockedEvent {
+    pub resource_address: ResourceAddress,
+    pub amount_locked: Decimal,
+    pub total_locked_now: Decimal,
+    pub timestamp: Instant,
+    pub epoch: Epoch,
+}
+
+// Event emitted the first time the cumulative locked amount of a badge type reaches a milestone
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct MilestoneReachedEvent {
+    pub resource_address: ResourceAddress,
+    pub badge_type: V1BadgeType,
+    pub milestone: Decimal,
+    pub total_locked: Decimal,
+    // Sequence number of the lock that reached the milestone
+    pub sequence: u64,
+    pub timestamp: Instant,
+}
+
+// Event emitted once, when the vaults first hold the entire supply of both badge resources
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct V1FullyRelinquishedEvent {
+    pub admin_badges_locked: Decimal,
+    pub upgrade_badges_locked: Decimal,
+    // Sequence number of the lock that completed the supply
+    pub sequence: u64,
+    pub timestamp: Instant,
+}
+
+// Canonical status snapshot emitted on request, for indexers that only consume events
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct StatusSnapshotEvent {
+    pub admin_badges_locked: Decimal,
+    pub upgrade_badges_locked: Decimal,
+    // Percentage of each resource's supply snapshot locked (None if the snapshot is missing or
+    // zero)
+    pub admin_percent_locked: Option<Decimal>,
+    pub upgrade_percent_locked: Option<Decimal>,
+    pub unique_contributors: u64,
+    pub lock_count: u64,
+    pub admin_last_locked_at: Option<Instant>,
+    pub upgrade_last_locked_at: Option<Instant>,
+    // When the snapshot was taken
+    pub timestamp: Instant,
+}
+
+// Why a `try_lock_*` call returned the bucket instead of locking it
+#[derive(ScryptoSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum V1RejectionReason {
+    WrongResource,
+    FractionalAmount,
+    EmptyBucket,
+    CampaignWindowClosed,
+}
+
+// Event emitted when a `try_lock_*` call returns the bucket instead of locking it
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct DepositRejectedEvent {
+    // Badge type the call expected
+    pub badge_type: V1BadgeType,
+    // Resource of the rejected bucket
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+    pub reason: V1RejectionReason,
+    pub timestamp: Instant,
+}
+
+// Event emitted by `check_supply_integrity` for a badge resource whose total supply changed
+// since instantiation (e.g. more V1 badges were minted)
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct SupplyAnomalyEvent {
+    pub badge_type: V1BadgeType,
+    pub resource_address: ResourceAddress,
+    pub supply_at_instantiation: Option<Decimal>,
+    pub current_supply: Option<Decimal>,
+    pub timestamp: Instant,
+}
+
+// Event emitted once, when the campaign completes and receipt issuance is permanently sealed
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct RelinquishmentSealedEvent {
+    pub admin_badges_locked: Decimal,
+    pub upgrade_badges_locked: Decimal,
+    pub unique_contributors: u64,
+    pub lock_count: u64,
+    // Resource of the completion artifact, minted only with a completion condition
+    pub artifact_resource: Option<ResourceAddress>,
+    // Whether the campaign was completed by `finalize_with_attestation` rather than by a lock
+    pub attested: bool,
+    pub sealed_at: Instant,
+}
+
+// Event emitted the first time the cumulative locked amount of a badge type meets its target
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct TargetReachedEvent {
+    pub resource_address: ResourceAddress,
+    pub badge_type: V1BadgeType,
+    pub target: Decimal,
+    pub total_locked: Decimal,
+    // Sequence number of the lock that met the target
+    pub sequence: u64,
+    pub timestamp: Instant,
+}
+
+// Event emitted when a lock carries the cumulative locked amount of a badge type past another
+// multiple of its checkpoint interval
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct CheckpointEvent {
+    pub resource_address: ResourceAddress,
+    pub badge_type: V1BadgeType,
+    // Highest multiple of the interval reached by the lock
+    pub checkpoint: Decimal,
+    pub total_locked: Decimal,
+    // Sequence number of the lock that reached the checkpoint
+    pub sequence: u64,
+    pub timestamp: Instant,
+}
+
+// Event emitted the first time an account is attributed a lock
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct NewContributorEvent {
+    pub contributor: ComponentAddress,
+    // 1-based position of the account among all contributors
+    pub contributor_number: u64,
+    pub timestamp: Instant,
+}
+
+// Lifecycle of a staged lock
+#[derive(ScryptoSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum V1StagedLockStatus {
+    // Reclaimable by the depositor until `reclaimable_until`
+    Pending,
+    // Moved into the permanent vault
+    Locked,
+    // Returned to the depositor
+    Reclaimed,
+}
+
+// Badges staged with `stage_lock`, held in a pending vault until confirmed, finalized or reclaimed
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct V1StagedLock {
+    pub depositor: ComponentAddress,
+    pub badge_type: V1BadgeType,
+    pub amount: Decimal,
+    pub staged_at: Instant,
+    pub reclaimable_until: Instant,
+    pub status: V1StagedLockStatus,
+}
+
+// Event emitted when badges are staged for locking
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct LockStagedEvent {
+    pub staged_lock_id: u64,
+    pub depositor: ComponentAddress,
+    pub badge_type: V1BadgeType,
+    pub amount: Decimal,
+    pub reclaimable_until: Instant,
+}
+
+// Event emitted when staged badges are returned to their depositor
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct StagedLockReclaimedEvent {
+    pub staged_lock_id: u64,
+    pub depositor: ComponentAddress,
+    pub badge_type: V1BadgeType,
+    pub amount: Decimal,
+    pub timestamp: Instant,
+}
+
+// An official announcement stored on the notice board
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct Notice {
+    pub notice_id: u64,
+    pub title: String,
+    pub body_hash: Hash,
+    pub posted_at: Instant,
+    // Local ids of the admin badges presented when posting (empty for fungible badges)
+    pub poster_badge_ids: Vec<NonFungibleLocalId>,
+}
+
+// Event emitted when an official notice is posted
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct NoticePostedEvent {
+    pub notice_id: u64,
+    pub title: String,
+    pub body_hash: Hash,
+    pub timestamp: Instant,
+}
+
+// Event emitted when RELINQUISHED tokens are burned by their holder
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct RelinquishedTokensBurnedEvent {
+    pub amount: Decimal,
+    pub timestamp: Instant,
+}
+
+// Event emitted when the final report is minted
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct FinalReportMintedEvent {
+    pub report_resource: ResourceAddress,
+    pub archive_account: ComponentAddress,
+    pub history_root: Hash,
     pub timestamp: Instant,
 }
 
 #[blueprint]
-#[events(V1AdminBadgesLockedEvent, V1UpgradeBadgeLockedEvent)]
+#[events(
+    V1LockerInstantiatedEvent,
+    V1AdminBadgesLockedEvent,
+    V1UpgradeBadgeLockedEvent,
+    V1BadgesLockedEvent,
+    OtherV1ResourceLockedEvent,
+    MilestoneReachedEvent,
+    TargetReachedEvent,
+    CheckpointEvent,
+    NewContributorEvent,
+    V1FullyRelinquishedEvent,
+    StatusSnapshotEvent,
+    DepositRejectedEvent,
+    SupplyAnomalyEvent,
+    RelinquishmentSealedEvent,
+    LockStagedEvent,
+    StagedLockReclaimedEvent,
+    NoticePostedEvent,
+    RelinquishedTokensBurnedEvent,
+    FinalReportMintedEvent
+)]
 mod rns_v1_badge_lockers {
     use super::*;
 
+    // Access model stated explicitly: anyone may instantiate and every method is public.
+    // No roles are defined, so no account holds special privileges over the component.
+    enable_function_auth! {
+        instantiate => rule!(allow_all);
+        instantiate_and_lock => rule!(allow_all);
+    }
+
+    enable_method_auth! {
+        methods {
+            lock_admin_badges => PUBLIC;
+            lock_upgrade_badges => PUBLIC;
+            lock_admin_badges_for => PUBLIC;
+            lock_upgrade_badges_for => PUBLIC;
+            lock_admin_badges_for_many => PUBLIC;
+            lock_upgrade_badges_for_many => PUBLIC;
+            lock_badges => PUBLIC;
+            lock_all => PUBLIC;
+            lock_admin_badges_with_memo => PUBLIC;
+            lock_upgrade_badges_with_memo => PUBLIC;
+            lock_other_v1_resource => PUBLIC;
+            stage_lock => PUBLIC;
+            confirm_lock => PUBLIC;
+            reclaim_staged_lock => PUBLIC;
+            finalize_staged_lock => PUBLIC;
+            get_staged_lock => PUBLIC;
+            try_lock_admin_badges => PUBLIC;
+            try_lock_upgrade_badges => PUBLIC;
+            claim_commemorative_nft => PUBLIC;
+            burn_derivative => PUBLIC;
+            post_notice => PUBLIC;
+            get_notices => PUBLIC;
+            get_lock_status => PUBLIC;
+            get_lock_status_v2 => PUBLIC;
+            get_component_info => PUBLIC;
+            preview_lock => PUBLIC;
+            describe => PUBLIC;
+            get_status_json => PUBLIC;
+            get_locked_nft_ids => PUBLIC;
+            get_locked_nft_data => PUBLIC;
+            contains_nft => PUBLIC;
+            emit_status_snapshot => PUBLIC;
+            check_supply_integrity => PUBLIC;
+            finalize_with_attestation => PUBLIC;
+            mint_final_report => PUBLIC;
+            get_contributor_totals => PUBLIC;
+            get_contributor_stats => PUBLIC;
+            verify_contributor => PUBLIC;
+            top_contributors => PUBLIC;
+            get_contribution_digest => PUBLIC;
+            get_receipt_resource => PUBLIC;
+            get_relinquished_resource => PUBLIC;
+            get_receipt_locker => PUBLIC;
+            get_commemorative_resource => PUBLIC;
+            get_lock_record => PUBLIC;
+            get_lock_history => PUBLIC;
+            get_progress_points => PUBLIC;
+            get_epoch_stats => PUBLIC;
+            targets_met => PUBLIC;
+            percent_of_supply_locked => PUBLIC;
+            remaining_unlocked_supply => PUBLIC;
+            is_admin_fully_locked => PUBLIC;
+            is_upgrade_fully_locked => PUBLIC;
+            amount_locked_of => PUBLIC;
+            get_all_locked_resources => PUBLIC;
+            get_vault_ids => PUBLIC;
+        }
+    }
+
     // V1 Auth Relinquishment Contract
     //
-    // A minimal, standalone contract for permanently locking RNS V1 admin and upgrade badges.
+    // A standalone contract for permanently locking RNS V1 admin and upgrade badges.
     // This demonstrates irreversible commitment to the V2 upgrade by accepting V1 badges
-    // which can never be withdrawn.
+    // which, once locked, can never be withdrawn. Badges deposited with the opt-in
+    // `stage_lock` are held in staging vaults until locked and can be reclaimed by their
+    // depositor until then.
 
     pub struct V1AuthRelinquishment {
         // Vault holding permanently locked V1 admin badges
@@ -48,97 +896,1589 @@ mod rns_v1_badge_lockers {
 
         // Expected resource address for V1 upgrade badges (validated on deposit)
         v1_upgrade_badge_resource: ResourceAddress,
-    }
 
-    impl V1AuthRelinquishment {
-        // Instantiates the V1 Auth Relinquishment contract.
-        //
-        // # Arguments
-        // * `v1_admin_badge_resource` - Resource address of V1 admin badges that can be locked
-        // * `v1_upgrade_badge_resource` - Resource address of V1 upgrade badges that can be locked
-        //
-        // # Returns
-        // The instantiated component (no admin badge - zero admin capability)
-        pub fn instantiate(
-            v1_admin_badge_resource: ResourceAddress,
-            v1_upgrade_badge_resource: ResourceAddress,
-        ) -> Global<V1AuthRelinquishment> {
-            Self {
-                v1_admin_badges_vault: Vault::new(v1_admin_badge_resource),
-                v1_upgrade_badges_vault: Vault::new(v1_upgrade_badge_resource),
-                v1_admin_badge_resource,
-                v1_upgrade_badge_resource,
-            }
-            .instantiate()
-            .prepare_to_globalize(OwnerRole::None)
-            .metadata(metadata! {
-                init {
-                    "name" => "RNS V1 Auth Relinquishment", locked;
-                    "description" => "Permanently locks RNS V1 admin and upgrade badges to demonstrate irreversible commitment to V2.", locked;
-                    "tags" => ["rns", "v1", "deprecation", "lock"], locked;
-                }
-            })
-            .globalize()
-        }
+        // Whether fractional badge amounts are rejected on deposit
+        whole_units_only: bool,
 
-        // Permanently locks V1 admin badges into this contract.
-        //
-        // Any community member can contribute V1 admin badges which will be locked indefinitely.
-        // This is part of the V1 deprecation process to demonstrate commitment to V2.
-        //
-        // # Arguments
-        // * `v1_admin_badges` - Bucket containing V1 admin badges to lock
-        //
-        // # Panics
-        // * If the bucket contains the wrong resource type
-        pub fn lock_admin_badges(&mut self, v1_admin_badges: Bucket) {
-            assert_eq!(
-                v1_admin_badges.resource_address(),
-                self.v1_admin_badge_resource,
-                "Invalid V1 admin badge resource. Expected {:?}, received {:?}",
-                self.v1_admin_badge_resource,
-                v1_admin_badges.resource_address()
-            );
+        // Accounts designated as RNS team at instantiation
+        team_accounts: IndexSet<ComponentAddress>,
 
-            let locked_count = v1_admin_badges.amount();
+        // Admin badges locked by a team account, attributed with its owner badge proof
+        team_admin_badges_locked: Decimal,
 
-            self.v1_admin_badges_vault.put(v1_admin_badges);
+        // Upgrade badges locked by a team account, attributed with its owner badge proof
+        team_upgrade_badges_locked: Decimal,
 
-            Runtime::emit_event(V1AdminBadgesLockedEvent {
-                badges_locked: locked_count,
-                total_locked_now: self.v1_admin_badges_vault.amount(),
-                timestamp: Clock::current_time_rounded_to_minutes(),
-            });
-        }
+        // Lock statistics of each attributed contributing account
+        contributors: KeyValueStore<ComponentAddress, ContributorStats>,
 
-        // Permanently locks V1 upgrade badges into this contract.
-        //
-        // Any community member can contribute V1 upgrade badges which will be locked indefinitely.
-        // This is part of the V1 deprecation process to demonstrate commitment to V2.
-        //
-        // # Arguments
+        // Number of distinct accounts that have made attributed locks
+        unique_contributors: u64,
+
+        // Contributing accounts in order of first contribution, so the contribution map can
+        // be enumerated (key-value stores cannot be iterated on-ledger)
+        contributor_index: KeyValueStore<u64, ComponentAddress>,
+
+        // Top contributors by total badges locked (admin + upgrade), sorted descending and
+        // bounded by MAX_LEADERBOARD_SIZE. Ties keep the contributor who got there first.
+        leaderboard: Vec<(ComponentAddress, Decimal)>,
+
+        // Running lock statistics of admin badges
+        admin_lock_stats: BadgeLockStats,
+
+        // Running lock statistics of upgrade badges
+        upgrade_lock_stats: BadgeLockStats,
+
+        // Every lock, keyed by sequence number (assigned sequentially from 0)
+        lock_records: KeyValueStore<u64, LockRecord>,
+
+        // Number of locks recorded so far (the next sequence number)
+        lock_count: u64,
+
+        // Lock totals per epoch, for charting locking velocity
+        epoch_stats: KeyValueStore<Epoch, EpochStats>,
+
+        // Receipt NFTs minted to contributors who lock with an account proof
+        receipt_resource: ResourceAddress,
+
+        // Native account locker delivering receipts, holding them for claim by contributors whose
+        // accounts reject the deposit
+        receipt_locker: Global<AccountLocker>,
+
+        // Fungible RELINQUISHED tokens minted 1:1 for every badge locked and returned to the locker
+        relinquished_resource: ResourceAddress,
+
+        // Commemorative NFTs claimable once by every attributed contributor, keyed by account
+        commemorative_resource: ResourceAddress,
+
+        // Thresholds of the commemorative tiers
+        tier_thresholds: V1TierThresholds,
+
+        // Completion condition of the campaign, if any
+        completion_condition: Option<V1CompletionCondition>,
+
+        // When the campaign completed
+        completed_at: Option<Instant>,
+
+        // Resource of the one-off artifact minted at completion
+        fully_relinquished_artifact: Option<ResourceAddress>,
+
+        // Resource of the one-off final report, once minted
+        final_report: Option<ResourceAddress>,
+
+        // When the entire supply of both badge resources was first held in the vaults
+        entire_supply_locked_at: Option<Instant>,
+
+        // Total supply of the admin badge resource at instantiation (None if not tracked)
+        admin_supply_at_instantiation: Option<Decimal>,
+
+        // Total supply of the upgrade badge resource at instantiation (None if not tracked)
+        upgrade_supply_at_instantiation: Option<Decimal>,
+
+        // Whether `check_supply_integrity` has ever found a supply differing from the snapshot
+        supply_anomaly_detected: bool,
+
+        // Recall and freeze exposure of the admin badge resource, read at instantiation
+        admin_badge_risks: V1ResourceRisks,
+
+        // Recall and freeze exposure of the upgrade badge resource, read at instantiation
+        upgrade_badge_risks: V1ResourceRisks,
+
+        // Epoch in which the component was instantiated
+        instantiated_epoch: Epoch,
+
+        // When the component was instantiated
+        instantiated_at: Instant,
+
+        // Admin badge milestones, ascending
+        admin_milestones: Vec<Decimal>,
+
+        // Upgrade badge milestones, ascending
+        upgrade_milestones: Vec<Decimal>,
+
+        // Admin badge lock target, if any
+        admin_target: Option<Decimal>,
+
+        // Upgrade badge lock target, if any
+        upgrade_target: Option<Decimal>,
+
+        // Epoch at which the campaign window opens, if any
+        campaign_start_epoch: Option<Epoch>,
+
+        // Epoch at which the campaign window closes, if any
+        campaign_end_epoch: Option<Epoch>,
+
+        // Whether locks are refused or accepted as late once the campaign window has closed
+        late_lock_policy: V1LateLockPolicy,
+
+        // The V2-launch attestation non-fungible that can complete the campaign, if any
+        attestation: Option<NonFungibleGlobalId>,
+
+        // Minimum amount of V1 admin badges a notice poster must prove
+        min_notice_proof_amount: Decimal,
+
+        // Minutes during which staged badges can be reclaimed by their depositor
+        staging_window_minutes: i64,
+
+        // Staged locks, keyed by id (assigned sequentially from 0)
+        staged_locks: KeyValueStore<u64, V1StagedLock>,
+
+        // Pending vaults of the staged locks, keyed by staged lock id. Emptied, not removed, once
+        // the lock is resolved.
+        staged_vaults: KeyValueStore<u64, Vault>,
+
+        // Number of locks staged so far (the next staged lock id)
+        staged_lock_count: u64,
+
+        // Additional deprecated V1 resources accepted by `lock_other_v1_resource`, in
+        // configuration order
+        other_v1_resources: Vec<ResourceAddress>,
+
+        // Vaults holding permanently locked additional V1 resources, created at instantiation
+        other_v1_vaults: KeyValueStore<ResourceAddress, Vault>,
+
+        // Increment of cumulative locked admin badges between checkpoints, if any
+        admin_checkpoint_interval: Option<Decimal>,
+
+        // Increment of cumulative locked upgrade badges between checkpoints, if any
+        upgrade_checkpoint_interval: Option<Decimal>,
+
+        // Progress curve points, keyed by index (assigned sequentially from 0)
+        progress_points: KeyValueStore<u64, ProgressPoint>,
+
+        // Number of progress points recorded
+        progress_point_count: u64,
+
+        // Official notices, keyed by notice id (assigned sequentially from 0)
+        notices: KeyValueStore<u64, Notice>,
+
+        // Number of notices posted so far (bounded by MAX_NOTICES)
+        notice_count: u64,
+    }
+
+    impl V1AuthRelinquishment {
+        // Instantiates the V1 Auth Relinquishment contract.
+        //
+        // Badge resources whose recaller or freezer roles are live are accepted, as the locker
+        // cannot change them, but flagged in the status' `admin_badge_risks` and
+        // `upgrade_badge_risks`.
+        //
+        // # Arguments
+        // * `v1_admin_badge_resource` - Resource address of V1 admin badges that can be locked
+        // * `v1_upgrade_badge_resource` - Resource address of V1 upgrade badges that can be locked
+        // * `config` - Wallet display metadata and other instantiation-time settings
+        //
+        // # Returns
+        // The instantiated component (no admin badge - zero admin capability)
+        //
+        // # Panics
+        // * If the tier thresholds are not positive and strictly ascending
+        // * If either milestone list is not positive and strictly ascending
+        // * If a lock target is not positive
+        // * If the staging window is not positive
+        // * If an additional V1 resource is a badge resource or listed twice
+        // * If a checkpoint interval is not positive
+        // * If the campaign start epoch is not before the end epoch
+        // * If the minimum notice proof amount is not positive
+        pub fn instantiate(
+            v1_admin_badge_resource: ResourceAddress,
+            v1_upgrade_badge_resource: ResourceAddress,
+            config: V1LockerConfig,
+        ) -> Global<V1AuthRelinquishment> {
+            let thresholds = &config.tier_thresholds;
+            assert!(
+                thresholds.bronze.is_positive()
+                    && thresholds.bronze < thresholds.silver
+                    && thresholds.silver < thresholds.gold,
+                "Tier thresholds must be positive and strictly ascending"
+            );
+            assert!(
+                is_strictly_ascending(&config.admin_milestones)
+                    && is_strictly_ascending(&config.upgrade_milestones),
+                "Milestones must be positive and strictly ascending"
+            );
+            assert!(
+                [config.admin_target, config.upgrade_target]
+                    .iter()
+                    .flatten()
+                    .all(|target| target.is_positive()),
+                "Lock targets must be positive"
+            );
+            assert!(
+                config.staging_window_minutes > 0,
+                "Staging window must be positive"
+            );
+            let other_v1_resources: IndexSet<ResourceAddress> =
+                config.other_v1_resources.iter().copied().collect();
+            assert!(
+                other_v1_resources.len() == config.other_v1_resources.len()
+                    && !other_v1_resources.contains(&v1_admin_badge_resource)
+                    && !other_v1_resources.contains(&v1_upgrade_badge_resource),
+                "Additional V1 resources must be distinct from each other and the badge resources"
+            );
+            assert!(
+                [
+                    config.admin_checkpoint_interval,
+                    config.upgrade_checkpoint_interval
+                ]
+                .iter()
+                .flatten()
+                .all(|interval| interval.is_positive()),
+                "Checkpoint intervals must be positive"
+            );
+            assert!(
+                config.min_notice_proof_amount.is_positive(),
+                "Minimum notice proof amount must be positive"
+            );
+            if let (Some(start_epoch), Some(end_epoch)) =
+                (config.campaign_start_epoch, config.campaign_end_epoch)
+            {
+                assert!(
+                    start_epoch < end_epoch,
+                    "Campaign start epoch must be before the end epoch"
+                );
+            }
+            let other_v1_vaults = KeyValueStore::new();
+            for resource in other_v1_resources.iter() {
+                other_v1_vaults.insert(*resource, Vault::new(*resource));
+            }
+
+            let (address_reservation, component_address) =
+                Runtime::allocate_component_address(V1AuthRelinquishment::blueprint_id());
+
+            let (locker_address_reservation, locker_address) = Runtime::allocate_component_address(
+                BlueprintId::new(&LOCKER_PACKAGE, ACCOUNT_LOCKER_BLUEPRINT),
+            );
+
+            // Only the component can mint and update receipts. Soulbound receipts can only be
+            // withdrawn by the receipt locker, to release receipts it holds to their claimant;
+            // deposits stay open so receipts can be delivered.
+            let receipt_withdraw_rule = if config.soulbound_receipts {
+                rule!(require(global_caller(locker_address)))
+            } else {
+                rule!(allow_all)
+            };
+            let receipt_resource =
+                ResourceBuilder::new_bytes_non_fungible::<V1RelinquishmentReceipt>(OwnerRole::None)
+                    .metadata(metadata! {
+                        init {
+                            "name" => config.receipt_branding.name, locked;
+                            "description" => config.receipt_branding.description, locked;
+                            "icon_url" => config.receipt_branding.icon_url, locked;
+                            "info_url" => config.receipt_branding.info_url, locked;
+                            "tags" => ["rns", "v1", "receipt"], locked;
+                        }
+                    })
+                    .mint_roles(mint_roles! {
+                        minter => rule!(require(global_caller(component_address)));
+                        minter_updater => rule!(deny_all);
+                    })
+                    .withdraw_roles(withdraw_roles! {
+                        withdrawer => receipt_withdraw_rule;
+                        withdrawer_updater => rule!(deny_all);
+                    })
+                    .non_fungible_data_update_roles(non_fungible_data_update_roles! {
+                        non_fungible_data_updater => rule!(require(global_caller(component_address)));
+                        non_fungible_data_updater_updater => rule!(deny_all);
+                    })
+                    .create_with_no_initial_supply()
+                    .address();
+
+            let relinquished_resource = ResourceBuilder::new_fungible(OwnerRole::None)
+                .divisibility(DIVISIBILITY_MAXIMUM)
+                .metadata(metadata! {
+                    init {
+                        "name" => "RNS V1 Relinquished", locked;
+                        "symbol" => "RELINQUISHED", locked;
+                        "description" => "Minted 1:1 for every RNS V1 badge permanently locked.", locked;
+                        "tags" => ["rns", "v1", "receipt"], locked;
+                    }
+                })
+                .mint_roles(mint_roles! {
+                    minter => rule!(require(global_caller(component_address)));
+                    minter_updater => rule!(deny_all);
+                })
+                .burn_roles(burn_roles! {
+                    burner => rule!(require(global_caller(component_address)));
+                    burner_updater => rule!(deny_all);
+                })
+                .create_with_no_initial_supply()
+                .address();
+
+            let commemorative_resource = ResourceBuilder::new_bytes_non_fungible::<
+                V1CommemorativeNft,
+            >(OwnerRole::None)
+            .metadata(metadata! {
+                init {
+                    "name" => config.commemorative_branding.name, locked;
+                    "description" => config.commemorative_branding.description, locked;
+                    "icon_url" => config.commemorative_branding.icon_url, locked;
+                    "info_url" => config.commemorative_branding.info_url, locked;
+                    "tags" => ["rns", "v1", "commemorative"], locked;
+                }
+            })
+            .mint_roles(mint_roles! {
+                minter => rule!(require(global_caller(component_address)));
+                minter_updater => rule!(deny_all);
+            })
+            .non_fungible_data_update_roles(non_fungible_data_update_roles! {
+                non_fungible_data_updater => rule!(require(global_caller(component_address)));
+                non_fungible_data_updater_updater => rule!(deny_all);
+            })
+            .create_with_no_initial_supply()
+            .address();
+
+            let component = Self {
+                v1_admin_badges_vault: Vault::new(v1_admin_badge_resource),
+                v1_upgrade_badges_vault: Vault::new(v1_upgrade_badge_resource),
+                v1_admin_badge_resource,
+                v1_upgrade_badge_resource,
+                whole_units_only: config.whole_units_only,
+                team_accounts: config.team_accounts.into_iter().collect(),
+                team_admin_badges_locked: Decimal::ZERO,
+                team_upgrade_badges_locked: Decimal::ZERO,
+                contributors: KeyValueStore::new(),
+                unique_contributors: 0,
+                contributor_index: KeyValueStore::new(),
+                leaderboard: Vec::new(),
+                admin_lock_stats: BadgeLockStats::default(),
+                upgrade_lock_stats: BadgeLockStats::default(),
+                lock_records: KeyValueStore::new(),
+                lock_count: 0,
+                epoch_stats: KeyValueStore::new(),
+                receipt_resource,
+                receipt_locker: Blueprint::<AccountLocker>::instantiate(
+                    OwnerRole::None,
+                    rule!(require(global_caller(component_address))),
+                    rule!(deny_all),
+                    rule!(deny_all),
+                    rule!(deny_all),
+                    Some(locker_address_reservation),
+                ),
+                relinquished_resource,
+                commemorative_resource,
+                tier_thresholds: config.tier_thresholds,
+                completion_condition: config.completion_condition,
+                completed_at: None,
+                fully_relinquished_artifact: None,
+                final_report: None,
+                entire_supply_locked_at: None,
+                admin_supply_at_instantiation: ResourceManager::from(v1_admin_badge_resource)
+                    .total_supply(),
+                upgrade_supply_at_instantiation: ResourceManager::from(v1_upgrade_badge_resource)
+                    .total_supply(),
+                supply_anomaly_detected: false,
+                admin_badge_risks: resource_risks(v1_admin_badge_resource),
+                upgrade_badge_risks: resource_risks(v1_upgrade_badge_resource),
+                instantiated_epoch: Runtime::current_epoch(),
+                instantiated_at: Clock::current_time_rounded_to_seconds(),
+                admin_milestones: config.admin_milestones,
+                upgrade_milestones: config.upgrade_milestones,
+                admin_target: config.admin_target,
+                upgrade_target: config.upgrade_target,
+                campaign_start_epoch: config.campaign_start_epoch,
+                campaign_end_epoch: config.campaign_end_epoch,
+                late_lock_policy: config.late_lock_policy,
+                attestation: config.attestation,
+                min_notice_proof_amount: config.min_notice_proof_amount,
+                staging_window_minutes: config.staging_window_minutes,
+                staged_locks: KeyValueStore::new(),
+                staged_vaults: KeyValueStore::new(),
+                staged_lock_count: 0,
+                other_v1_resources: config.other_v1_resources,
+                other_v1_vaults,
+                admin_checkpoint_interval: config.admin_checkpoint_interval,
+                upgrade_checkpoint_interval: config.upgrade_checkpoint_interval,
+                progress_points: KeyValueStore::new(),
+                progress_point_count: 0,
+                notices: KeyValueStore::new(),
+                notice_count: 0,
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .with_address(address_reservation)
+            .metadata(metadata! {
+                init {
+                    "name" => "RNS V1 Auth Relinquishment", locked;
+                    "description" => "Permanently locks RNS V1 admin and upgrade badges to demonstrate irreversible commitment to V2.", locked;
+                    "tags" => ["rns", "v1", "deprecation", "lock"], locked;
+                    "dapp_definition" => config.dapp_definition, locked;
+                    "claimed_websites" => config.claimed_websites, locked;
+                    "claimed_entities" => config.claimed_entities, locked;
+                    "icon_url" => config.icon_url, locked;
+                    "info_url" => config.info_url, locked;
+                }
+            })
+            .globalize();
+
+            Runtime::emit_event(V1LockerInstantiatedEvent {
+                component_address,
+                admin_badge_resource: v1_admin_badge_resource,
+                upgrade_badge_resource: v1_upgrade_badge_resource,
+                timestamp: Clock::current_time_rounded_to_seconds(),
+            });
+
+            component
+        }
+
+        // Instantiates the V1 Auth Relinquishment contract and immediately locks the given badges,
+        // so a deployer can deploy and relinquish atomically in one call.
+        //
+        // # Arguments
+        // * `v1_admin_badge_resource` - Resource address of V1 admin badges that can be locked
+        // * `v1_upgrade_badge_resource` - Resource address of V1 upgrade badges that can be locked
+        // * `config` - Wallet display metadata and other instantiation-time settings
+        // * `v1_admin_badges` - Optional bucket containing V1 admin badges to lock
+        // * `v1_upgrade_badges` - Optional bucket containing V1 upgrade badges to lock
+        // * `confirmation` - Must equal IRREVERSIBILITY_CONFIRMATION
+        //
+        // # Returns
+        // The instantiated component and RELINQUISHED tokens, one per badge locked
+        //
+        // # Panics
+        // * On any condition under which `instantiate` or `lock_all` panics
+        pub fn instantiate_and_lock(
+            v1_admin_badge_resource: ResourceAddress,
+            v1_upgrade_badge_resource: ResourceAddress,
+            config: V1LockerConfig,
+            v1_admin_badges: Option<Bucket>,
+            v1_upgrade_badges: Option<Bucket>,
+            confirmation: String,
+        ) -> (Global<V1AuthRelinquishment>, Bucket) {
+            let component =
+                Self::instantiate(v1_admin_badge_resource, v1_upgrade_badge_resource, config);
+            let relinquished = component.lock_all(v1_admin_badges, v1_upgrade_badges, confirmation);
+
+            (component, relinquished)
+        }
+
+        // Permanently locks V1 admin badges into this contract.
+        //
+        // Any community member can contribute V1 admin badges which will be locked indefinitely.
+        // This is part of the V1 deprecation process to demonstrate commitment to V2.
+        // Attributed locks mint a receipt NFT to the contributing account, or update the one it
+        // already holds.
+        //
+        // # Arguments
+        // * `v1_admin_badges` - Bucket containing V1 admin badges to lock
+        // * `account_proof` - Optional proof of an account owner badge attributing the lock
+        // * `confirmation` - Must equal IRREVERSIBILITY_CONFIRMATION
+        //
+        // # Returns
+        // RELINQUISHED tokens, one per badge locked
+        //
+        // # Panics
+        // * If the confirmation does not equal IRREVERSIBILITY_CONFIRMATION
+        // * If the bucket contains the wrong resource type
+        // * If the bucket is empty
+        // * If whole units are enforced and the amount is fractional
+        // * If the campaign window has not opened, or has closed and late locks are refused
+        // * If the account proof is not of a single account owner badge
+        pub fn lock_admin_badges(
+            &mut self,
+            v1_admin_badges: Bucket,
+            account_proof: Option<Proof>,
+            confirmation: String,
+        ) -> Bucket {
+            assert_irreversibility_confirmed(&confirmation);
+            self.lock_with_proof(V1BadgeType::Admin, v1_admin_badges, account_proof)
+        }
+
+        // Permanently locks V1 upgrade badges into this contract.
+        //
+        // Any community member can contribute V1 upgrade badges which will be locked indefinitely.
+        // This is part of the V1 deprecation process to demonstrate commitment to V2.
+        // Attributed locks mint a receipt NFT to the contributing account, or update the one it
+        // already holds.
+        //
+        // # Arguments
+        // * `v1_upgrade_badges` - Bucket containing V1 upgrade badges to lock
+        // * `account_proof` - Optional proof of an account owner badge attributing the lock
+        // * `confirmation` - Must equal IRREVERSIBILITY_CONFIRMATION
+        //
+        // # Returns
+        // RELINQUISHED tokens, one per badge locked
+        //
+        // # Panics
+        // * If the confirmation does not equal IRREVERSIBILITY_CONFIRMATION
+        // * If the bucket contains the wrong resource type
+        // * If the bucket is empty
+        // * If whole units are enforced and the amount is fractional
+        // * If the campaign window has not opened, or has closed and late locks are refused
+        // * If the account proof is not of a single account owner badge
+        pub fn lock_upgrade_badges(
+            &mut self,
+            v1_upgrade_badges: Bucket,
+            account_proof: Option<Proof>,
+            confirmation: String,
+        ) -> Bucket {
+            assert_irreversibility_confirmed(&confirmation);
+            self.lock_with_proof(V1BadgeType::Upgrade, v1_upgrade_badges, account_proof)
+        }
+
+        // Permanently locks V1 admin badges on behalf of another account.
+        //
+        // Lets custodians or helpers lock badges while crediting the contribution to the
+        // beneficiary, whose contributor statistics and lock event reflect the lock.
+        //
+        // # Arguments
+        // * `beneficiary` - Account the contribution is attributed to
+        // * `v1_admin_badges` - Bucket containing V1 admin badges to lock
+        // * `confirmation` - Must equal IRREVERSIBILITY_CONFIRMATION
+        //
+        // # Returns
+        // RELINQUISHED tokens, one per badge locked, returned to the caller
+        //
+        // # Panics
+        // * If the confirmation does not equal IRREVERSIBILITY_CONFIRMATION
+        // * If the bucket contains the wrong resource type
+        // * If the bucket is empty
+        // * If whole units are enforced and the amount is fractional
+        // * If the campaign window has not opened, or has closed and late locks are refused
+        pub fn lock_admin_badges_for(
+            &mut self,
+            beneficiary: ComponentAddress,
+            v1_admin_badges: Bucket,
+            confirmation: String,
+        ) -> Bucket {
+            assert_irreversibility_confirmed(&confirmation);
+            let record = self.lock(V1BadgeType::Admin, v1_admin_badges, Some(beneficiary));
+            self.mint_relinquished(record.amount)
+        }
+
+        // Permanently locks V1 upgrade badges on behalf of another account.
+        //
+        // See `lock_admin_badges_for`.
+        //
+        // # Arguments
+        // * `beneficiary` - Account the contribution is attributed to
+        // * `v1_upgrade_badges` - Bucket containing V1 upgrade badges to lock
+        // * `confirmation` - Must equal IRREVERSIBILITY_CONFIRMATION
+        //
+        // # Returns
+        // RELINQUISHED tokens, one per badge locked, returned to the caller
+        //
+        // # Panics
+        // * If the confirmation does not equal IRREVERSIBILITY_CONFIRMATION
+        // * If the bucket contains the wrong resource type
+        // * If the bucket is empty
+        // * If whole units are enforced and the amount is fractional
+        // * If the campaign window has not opened, or has closed and late locks are refused
+        pub fn lock_upgrade_badges_for(
+            &mut self,
+            beneficiary: ComponentAddress,
+            v1_upgrade_badges: Bucket,
+            confirmation: String,
+        ) -> Bucket {
+            assert_irreversibility_confirmed(&confirmation);
+            let record = self.lock(V1BadgeType::Upgrade, v1_upgrade_badges, Some(beneficiary));
+            self.mint_relinquished(record.amount)
+        }
+
+        // Permanently locks V1 admin badges on behalf of many accounts at once.
+        //
+        // Lets exchanges or multisig custodians lock a single bucket while crediting each user
+        // with their share. Each share is locked and attributed (with its own event) in turn.
+        //
+        // # Arguments
+        // * `v1_admin_badges` - Bucket containing V1 admin badges to lock
+        // * `attributions` - `(beneficiary, amount)` pairs that must sum to the bucket amount
+        // * `confirmation` - Must equal IRREVERSIBILITY_CONFIRMATION
+        //
+        // # Returns
+        // RELINQUISHED tokens, one per badge locked, returned to the caller
+        //
+        // # Panics
+        // * If the confirmation does not equal IRREVERSIBILITY_CONFIRMATION
+        // * If the bucket contains the wrong resource type
+        // * If `attributions` is empty, has a non-positive amount or does not sum to the bucket
+        // * If whole units are enforced and any share is fractional
+        // * If the campaign window has not opened, or has closed and late locks are refused
+        pub fn lock_admin_badges_for_many(
+            &mut self,
+            v1_admin_badges: Bucket,
+            attributions: Vec<(ComponentAddress, Decimal)>,
+            confirmation: String,
+        ) -> Bucket {
+            assert_irreversibility_confirmed(&confirmation);
+            self.lock_for_many(V1BadgeType::Admin, v1_admin_badges, attributions)
+        }
+
+        // Permanently locks V1 upgrade badges on behalf of many accounts at once.
+        //
+        // See `lock_admin_badges_for_many`.
+        //
+        // # Arguments
         // * `v1_upgrade_badges` - Bucket containing V1 upgrade badges to lock
+        // * `attributions` - `(beneficiary, amount)` pairs that must sum to the bucket amount
+        // * `confirmation` - Must equal IRREVERSIBILITY_CONFIRMATION
+        //
+        // # Returns
+        // RELINQUISHED tokens, one per badge locked, returned to the caller
+        //
+        // # Panics
+        // * If the confirmation does not equal IRREVERSIBILITY_CONFIRMATION
+        // * If the bucket contains the wrong resource type
+        // * If `attributions` is empty, has a non-positive amount or does not sum to the bucket
+        // * If whole units are enforced and any share is fractional
+        // * If the campaign window has not opened, or has closed and late locks are refused
+        pub fn lock_upgrade_badges_for_many(
+            &mut self,
+            v1_upgrade_badges: Bucket,
+            attributions: Vec<(ComponentAddress, Decimal)>,
+            confirmation: String,
+        ) -> Bucket {
+            assert_irreversibility_confirmed(&confirmation);
+            self.lock_for_many(V1BadgeType::Upgrade, v1_upgrade_badges, attributions)
+        }
+
+        // Permanently locks buckets of either badge type, routing each into the vault of its
+        // resource.
+        //
+        // Lets a manifest lock both badge types in one call without managing the worktop per
+        // badge type. Each bucket is locked (with its own event) in turn.
+        //
+        // # Arguments
+        // * `buckets` - Buckets of V1 admin or upgrade badges to lock
+        // * `confirmation` - Must equal IRREVERSIBILITY_CONFIRMATION
+        //
+        // # Returns
+        // RELINQUISHED tokens, one per badge locked
+        //
+        // # Panics
+        // * If the confirmation does not equal IRREVERSIBILITY_CONFIRMATION
+        // * If `buckets` is empty
+        // * If a bucket contains neither badge resource
+        // * If a bucket is empty
+        // * If whole units are enforced and an amount is fractional
+        // * If the campaign window has not opened, or has closed and late locks are refused
+        pub fn lock_badges(&mut self, buckets: Vec<Bucket>, confirmation: String) -> Bucket {
+            assert_irreversibility_confirmed(&confirmation);
+            assert!(!buckets.is_empty(), "At least one bucket is required");
+
+            let mut locked_total = Decimal::ZERO;
+            for badges in buckets {
+                let badge_type = self.badge_type_of_bucket(&badges);
+                locked_total += self.lock(badge_type, badges, None).amount;
+            }
+
+            self.mint_relinquished(locked_total)
+        }
+
+        // Permanently locks admin and upgrade badges in one call, so a holder of both badge types
+        // can relinquish everything at once.
+        //
+        // When both buckets are given, a single `V1BadgesLockedEvent` carrying both amounts is
+        // emitted instead of the two per-type lock events.
+        //
+        // # Arguments
+        // * `v1_admin_badges` - Optional bucket containing V1 admin badges to lock
+        // * `v1_upgrade_badges` - Optional bucket containing V1 upgrade badges to lock
+        // * `confirmation` - Must equal IRREVERSIBILITY_CONFIRMATION
+        //
+        // # Returns
+        // RELINQUISHED tokens, one per badge locked
+        //
+        // # Panics
+        // * If the confirmation does not equal IRREVERSIBILITY_CONFIRMATION
+        // * If neither bucket is given
+        // * If a bucket contains the wrong resource type
+        // * If a bucket is empty
+        // * If whole units are enforced and an amount is fractional
+        // * If the campaign window has not opened, or has closed and late locks are refused
+        pub fn lock_all(
+            &mut self,
+            v1_admin_badges: Option<Bucket>,
+            v1_upgrade_badges: Option<Bucket>,
+            confirmation: String,
+        ) -> Bucket {
+            assert_irreversibility_confirmed(&confirmation);
+            let (admin_badges, upgrade_badges) = match (v1_admin_badges, v1_upgrade_badges) {
+                (Some(admin_badges), Some(upgrade_badges)) => (admin_badges, upgrade_badges),
+                (Some(badges), None) => {
+                    return self.lock_with_proof(V1BadgeType::Admin, badges, None)
+                }
+                (None, Some(badges)) => {
+                    return self.lock_with_proof(V1BadgeType::Upgrade, badges, None)
+                }
+                (None, None) => panic!("At least one bucket is required"),
+            };
+
+            let admin_record = self.lock_inner(V1BadgeType::Admin, admin_badges, None, None, false);
+            let upgrade_record =
+                self.lock_inner(V1BadgeType::Upgrade, upgrade_badges, None, None, false);
+            Runtime::emit_event(V1BadgesLockedEvent {
+                admin_badges_locked: admin_record.amount,
+                upgrade_badges_locked: upgrade_record.amount,
+                admin_total_locked_now: self.v1_admin_badges_vault.amount(),
+                upgrade_total_locked_now: self.v1_upgrade_badges_vault.amount(),
+                timestamp: upgrade_record.timestamp,
+                epoch: upgrade_record.epoch,
+                sequences: vec![admin_record.seq, upgrade_record.seq],
+                late: upgrade_record.late,
+            });
+
+            self.mint_relinquished(admin_record.amount + upgrade_record.amount)
+        }
+
+        // Permanently locks V1 admin badges with a public memo.
+        //
+        // Lets contributors attach a statement of support that is stored with the lock record and
+        // included in the lock event.
+        //
+        // # Arguments
+        // * `v1_admin_badges` - Bucket containing V1 admin badges to lock
+        // * `memo` - Statement to attach (at most MAX_MEMO_LENGTH bytes)
+        // * `confirmation` - Must equal IRREVERSIBILITY_CONFIRMATION
+        //
+        // # Returns
+        // RELINQUISHED tokens, one per badge locked
+        //
+        // # Panics
+        // * If the confirmation does not equal IRREVERSIBILITY_CONFIRMATION
+        // * If the memo is empty or longer than MAX_MEMO_LENGTH bytes
+        // * If the bucket contains the wrong resource type
+        // * If the bucket is empty
+        // * If whole units are enforced and the amount is fractional
+        // * If the campaign window has not opened, or has closed and late locks are refused
+        pub fn lock_admin_badges_with_memo(
+            &mut self,
+            v1_admin_badges: Bucket,
+            memo: String,
+            confirmation: String,
+        ) -> Bucket {
+            assert_irreversibility_confirmed(&confirmation);
+            self.lock_with_memo(V1BadgeType::Admin, v1_admin_badges, memo)
+        }
+
+        // Permanently locks V1 upgrade badges with a public memo.
+        //
+        // See `lock_admin_badges_with_memo`.
+        //
+        // # Arguments
+        // * `v1_upgrade_badges` - Bucket containing V1 upgrade badges to lock
+        // * `memo` - Statement to attach (at most MAX_MEMO_LENGTH bytes)
+        // * `confirmation` - Must equal IRREVERSIBILITY_CONFIRMATION
+        //
+        // # Returns
+        // RELINQUISHED tokens, one per badge locked
         //
         // # Panics
+        // * If the confirmation does not equal IRREVERSIBILITY_CONFIRMATION
+        // * If the memo is empty or longer than MAX_MEMO_LENGTH bytes
         // * If the bucket contains the wrong resource type
-        pub fn lock_upgrade_badges(&mut self, v1_upgrade_badges: Bucket) {
+        // * If the bucket is empty
+        // * If whole units are enforced and the amount is fractional
+        // * If the campaign window has not opened, or has closed and late locks are refused
+        pub fn lock_upgrade_badges_with_memo(
+            &mut self,
+            v1_upgrade_badges: Bucket,
+            memo: String,
+            confirmation: String,
+        ) -> Bucket {
+            assert_irreversibility_confirmed(&confirmation);
+            self.lock_with_memo(V1BadgeType::Upgrade, v1_upgrade_badges, memo)
+        }
+
+        // Permanently locks an additional deprecated V1 resource configured at instantiation,
+        // so stray V1-era badges can be retired in the same component.
+        //
+        // These resources are not V1 admin or upgrade badges, so they are not recorded as locks,
+        // attributed or rewarded with RELINQUISHED tokens.
+        //
+        // # Arguments
+        // * `v1_resources` - Bucket containing one of the additional V1 resources
+        // * `confirmation` - Must equal IRREVERSIBILITY_CONFIRMATION
+        //
+        // # Panics
+        // * If the confirmation does not equal IRREVERSIBILITY_CONFIRMATION
+        // * If the resource is not one of the additional V1 resources
+        // * If the bucket is empty
+        // * If the campaign window has not opened, or has closed and late locks are refused
+        pub fn lock_other_v1_resource(&mut self, v1_resources: Bucket, confirmation: String) {
+            assert_irreversibility_confirmed(&confirmation);
+            let resource_address = v1_resources.resource_address();
+            let amount_locked = v1_resources.amount();
+            assert!(
+                amount_locked.is_positive(),
+                "Cannot lock an empty bucket of {:?}",
+                resource_address
+            );
+            self.assert_accepting_locks();
+
+            let total_locked_now = {
+                let mut vault = self
+                    .other_v1_vaults
+                    .get_mut(&resource_address)
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "{:?} is not an additional V1 resource of this locker",
+                            resource_address
+                        )
+                    });
+                vault.put(v1_resources);
+                vault.amount()
+            };
+
+            Runtime::emit_event(OtherV1ResourceLockedEvent {
+                resource_address,
+                amount_locked,
+                total_locked_now,
+                timestamp: Clock::current_time_rounded_to_seconds(),
+                epoch: Runtime::current_epoch(),
+            });
+        }
+
+        // Stages V1 badges for locking, holding them in a pending vault that the depositor can
+        // reclaim from for the configured staging window.
+        //
+        // This is the first of two steps, and locks nothing yet: no lock is recorded and no
+        // RELINQUISHED tokens are minted. In the second step the badges are moved from the
+        // pending vault into the permanent vault and locked like any attributed lock, either by
+        // the depositor with `confirm_lock` during the window or by anyone with
+        // `finalize_staged_lock` once it has passed. This protects against mistakes while
+        // preserving eventual irreversibility.
+        //
+        // # Arguments
+        // * `badges` - Bucket containing V1 admin or upgrade badges to stage
+        // * `account_proof` - Proof of the depositing account's owner badge
+        // * `confirmation` - Must equal IRREVERSIBILITY_CONFIRMATION
+        //
+        // # Returns
+        // The id of the staged lock
+        //
+        // # Panics
+        // * If the confirmation does not equal IRREVERSIBILITY_CONFIRMATION
+        // * If the bucket contains neither badge resource
+        // * If the bucket is empty
+        // * If whole units are enforced and the amount is fractional
+        // * If the campaign window has not opened, or has closed and late locks are refused
+        // * If the account proof is not of a single account owner badge
+        pub fn stage_lock(
+            &mut self,
+            badges: Bucket,
+            account_proof: Proof,
+            confirmation: String,
+        ) -> u64 {
+            assert_irreversibility_confirmed(&confirmation);
+            let depositor = Self::contributor_from_proof(account_proof);
+            let badge_type = self.badge_type_of_bucket(&badges);
+            self.assert_lockable(badge_type, &badges);
+
+            let staged_at = Clock::current_time_rounded_to_seconds();
+            let staged_lock = V1StagedLock {
+                depositor,
+                badge_type,
+                amount: badges.amount(),
+                staged_at,
+                reclaimable_until: staged_at
+                    .add_minutes(self.staging_window_minutes)
+                    .expect("Staging window end should be representable"),
+                status: V1StagedLockStatus::Pending,
+            };
+
+            let staged_lock_id = self.staged_lock_count;
+            Runtime::emit_event(LockStagedEvent {
+                staged_lock_id,
+                depositor,
+                badge_type,
+                amount: staged_lock.amount,
+                reclaimable_until: staged_lock.reclaimable_until,
+            });
+            self.staged_locks.insert(staged_lock_id, staged_lock);
+            self.staged_vaults
+                .insert(staged_lock_id, Vault::with_bucket(badges));
+            self.staged_lock_count += 1;
+
+            staged_lock_id
+        }
+
+        // Confirms a staged lock, moving its badges into the permanent vault before the staging
+        // window has passed.
+        //
+        // The lock is attributed to the depositor, who receives a receipt as with
+        // `lock_admin_badges` / `lock_upgrade_badges`.
+        //
+        // # Arguments
+        // * `staged_lock_id` - Id returned by `stage_lock`
+        // * `account_proof` - Proof of the depositing account's owner badge
+        //
+        // # Returns
+        // RELINQUISHED tokens, one per badge locked
+        //
+        // # Panics
+        // * If the staged lock does not exist or is no longer pending
+        // * If the account proof is not of the depositor's owner badge
+        // * If the campaign no longer accepts locks (the depositor can then reclaim the badges)
+        pub fn confirm_lock(&mut self, staged_lock_id: u64, account_proof: Proof) -> Bucket {
+            let depositor = Self::contributor_from_proof(account_proof);
+            let (staged_lock, badges) = self.resolve_staged_lock(
+                staged_lock_id,
+                Some(depositor),
+                V1StagedLockStatus::Locked,
+            );
+
+            self.lock_attributed(staged_lock.badge_type, badges, Some(depositor))
+        }
+
+        // Returns staged badges to their depositor while the staging window is open, or at any
+        // time once the campaign no longer accepts locks, since they can then not be locked.
+        //
+        // # Arguments
+        // * `staged_lock_id` - Id returned by `stage_lock`
+        // * `account_proof` - Proof of the depositing account's owner badge
+        //
+        // # Returns
+        // The staged badges
+        //
+        // # Panics
+        // * If the staged lock does not exist or is no longer pending
+        // * If the account proof is not of the depositor's owner badge
+        // * If the staging window has passed while the campaign accepts locks
+        pub fn reclaim_staged_lock(&mut self, staged_lock_id: u64, account_proof: Proof) -> Bucket {
+            let depositor = Self::contributor_from_proof(account_proof);
+            let (staged_lock, badges) = self.resolve_staged_lock(
+                staged_lock_id,
+                Some(depositor),
+                V1StagedLockStatus::Reclaimed,
+            );
+
+            let now = Clock::current_time_rounded_to_seconds();
+            assert!(
+                now < staged_lock.reclaimable_until || !self.is_accepting_locks(),
+                "The staging window of staged lock {} has passed",
+                staged_lock_id
+            );
+
+            Runtime::emit_event(StagedLockReclaimedEvent {
+                staged_lock_id,
+                depositor,
+                badge_type: staged_lock.badge_type,
+                amount: staged_lock.amount,
+                timestamp: now,
+            });
+
+            badges
+        }
+
+        // Locks staged badges whose staging window has passed.
+        //
+        // Anyone can call this. The lock is attributed to the depositor, and the RELINQUISHED
+        // tokens are delivered to the depositor (through the receipt locker should the account
+        // reject them).
+        //
+        // # Arguments
+        // * `staged_lock_id` - Id returned by `stage_lock`
+        //
+        // # Panics
+        // * If the staged lock does not exist or is no longer pending
+        // * If the staging window has not passed yet
+        // * If the campaign no longer accepts locks (the depositor can then reclaim the badges)
+        pub fn finalize_staged_lock(&mut self, staged_lock_id: u64) {
+            let (staged_lock, badges) =
+                self.resolve_staged_lock(staged_lock_id, None, V1StagedLockStatus::Locked);
+            assert!(
+                Clock::current_time_rounded_to_seconds() >= staged_lock.reclaimable_until,
+                "Staged lock {} can still be reclaimed until {:?}",
+                staged_lock_id,
+                staged_lock.reclaimable_until
+            );
+
+            let depositor = staged_lock.depositor;
+            let relinquished =
+                self.lock_attributed(staged_lock.badge_type, badges, Some(depositor));
+            self.receipt_locker
+                .store(Global::<Account>::from(depositor), relinquished, true);
+        }
+
+        // Returns a staged lock.
+        //
+        // # Arguments
+        // * `staged_lock_id` - Id returned by `stage_lock`
+        //
+        // # Returns
+        // The staged lock, or None if no lock was staged under the id
+        pub fn get_staged_lock(&self, staged_lock_id: u64) -> Option<V1StagedLock> {
+            self.staged_locks
+                .get(&staged_lock_id)
+                .map(|staged_lock| staged_lock.clone())
+        }
+
+        // Locks V1 admin badges, returning the bucket instead of panicking if it cannot be locked.
+        //
+        // Lets composed manifests handle a rejected deposit gracefully instead of aborting. A
+        // `DepositRejectedEvent` is emitted for every rejection.
+        //
+        // # Arguments
+        // * `v1_admin_badges` - Bucket containing V1 admin badges to lock
+        //
+        // # Returns
+        // `Ok` with RELINQUISHED tokens if locked, or `Err` with the untouched bucket if it is of the
+        // wrong resource, empty or (with whole units enforced) a fractional amount
+        pub fn try_lock_admin_badges(&mut self, v1_admin_badges: Bucket) -> Result<Bucket, Bucket> {
+            self.try_lock(V1BadgeType::Admin, v1_admin_badges)
+        }
+
+        // Locks V1 upgrade badges, returning the bucket instead of panicking if it cannot be locked.
+        //
+        // See `try_lock_admin_badges`.
+        //
+        // # Arguments
+        // * `v1_upgrade_badges` - Bucket containing V1 upgrade badges to lock
+        //
+        // # Returns
+        // `Ok` with RELINQUISHED tokens if locked, or `Err` with the untouched bucket if it is of the
+        // wrong resource, empty or (with whole units enforced) a fractional amount
+        pub fn try_lock_upgrade_badges(
+            &mut self,
+            v1_upgrade_badges: Bucket,
+        ) -> Result<Bucket, Bucket> {
+            self.try_lock(V1BadgeType::Upgrade, v1_upgrade_badges)
+        }
+
+        // Claims the commemorative NFT of an attributed contributor.
+        //
+        // Eligibility is recorded at lock time, so contributors can claim whenever they like,
+        // including those credited through `_for` locks by a custodian. Each account can claim once.
+        // The NFT carries the tier reached so far and is upgraded as further locks cross thresholds.
+        //
+        // # Arguments
+        // * `account_proof` - Proof of the contributing account's owner badge
+        //
+        // # Returns
+        // The commemorative NFT, whose local id is the account's address bytes
+        //
+        // # Panics
+        // * If the proof is not of a single account owner badge
+        // * If the account has no attributed locks
+        // * If the account has already claimed
+        pub fn claim_commemorative_nft(&mut self, account_proof: Proof) -> Bucket {
+            let account = Self::contributor_from_proof(account_proof);
+            let stats = self.get_contributor_stats(account);
+            let first_locked_at = stats
+                .first_locked_at
+                .expect("Only accounts with attributed locks can claim a commemorative NFT");
+
+            let manager = ResourceManager::from(self.commemorative_resource);
+            let nft_id = commemorative_nft_id(account);
+            assert!(
+                !manager.non_fungible_exists(&nft_id),
+                "The commemorative NFT of {:?} has already been claimed",
+                account
+            );
+
+            manager.mint_non_fungible(
+                &nft_id,
+                V1CommemorativeNft {
+                    contributor: account,
+                    first_locked_at,
+                    claimed_at: Clock::current_time_rounded_to_seconds(),
+                    tier: self
+                        .tier_thresholds
+                        .tier_of(stats.admin_badges_locked + stats.upgrade_badges_locked),
+                },
+            )
+        }
+
+        // Burns RELINQUISHED tokens so holders can tidy their wallets.
+        //
+        // The tokens are only a receipt of past locks: burning them leaves the locked badges,
+        // lock totals and contribution records untouched.
+        //
+        // # Arguments
+        // * `tokens` - Bucket of RELINQUISHED tokens to burn
+        //
+        // # Panics
+        // * If the bucket does not contain RELINQUISHED tokens
+        // * If the bucket is empty
+        pub fn burn_derivative(&self, tokens: Bucket) {
             assert_eq!(
-                v1_upgrade_badges.resource_address(),
-                self.v1_upgrade_badge_resource,
-                "Invalid V1 upgrade badge resource. Expected {:?}, received {:?}",
-                self.v1_upgrade_badge_resource,
-                v1_upgrade_badges.resource_address()
+                tokens.resource_address(),
+                self.relinquished_resource,
+                "Only RELINQUISHED tokens can be burned"
+            );
+            let amount = tokens.amount();
+            assert!(amount > Decimal::ZERO, "Cannot burn an empty bucket");
+
+            tokens.burn();
+
+            Runtime::emit_event(RelinquishedTokensBurnedEvent {
+                amount,
+                timestamp: Clock::current_time_rounded_to_seconds(),
+            });
+        }
+
+        // Posts an official notice to the on-ledger notice board.
+        //
+        // Only the body hash is stored; the full text is published off-ledger and can be
+        // verified against it.
+        //
+        // # Arguments
+        // * `admin_proof` - Proof of at least the configured minimum amount of V1 admin badges
+        // * `title` - Short notice title (at most MAX_NOTICE_TITLE_LENGTH bytes)
+        // * `body_hash` - Hash of the full notice body
+        //
+        // # Panics
+        // * If the proof is not of the V1 admin badge resource or holds less than the minimum
+        // * If the title is empty or too long
+        // * If MAX_NOTICES notices have already been posted
+        pub fn post_notice(&mut self, admin_proof: Proof, title: String, body_hash: Hash) {
+            let admin_proof = admin_proof.check_with_message(
+                self.v1_admin_badge_resource,
+                "Notices can only be posted with a V1 admin badge proof",
+            );
+            assert!(
+                admin_proof.amount() >= self.min_notice_proof_amount,
+                "Notices require a proof of at least {} V1 admin badges, received {}",
+                self.min_notice_proof_amount,
+                admin_proof.amount()
+            );
+            assert!(
+                !title.is_empty() && title.len() <= MAX_NOTICE_TITLE_LENGTH,
+                "Notice title must be between 1 and {} bytes",
+                MAX_NOTICE_TITLE_LENGTH
+            );
+            assert!(
+                self.notice_count < MAX_NOTICES,
+                "The notice board is full ({} notices)",
+                MAX_NOTICES
+            );
+
+            let poster_badge_ids = if ResourceManager::from(self.v1_admin_badge_resource)
+                .resource_type()
+                .is_fungible()
+            {
+                Vec::new()
+            } else {
+                admin_proof
+                    .as_non_fungible()
+                    .non_fungible_local_ids()
+                    .into_iter()
+                    .collect()
+            };
+
+            let notice_id = self.notice_count;
+            let timestamp = Clock::current_time_rounded_to_seconds();
+
+            self.notices.insert(
+                notice_id,
+                Notice {
+                    notice_id,
+                    title: title.clone(),
+                    body_hash,
+                    posted_at: timestamp,
+                    poster_badge_ids,
+                },
             );
+            self.notice_count += 1;
+
+            Runtime::emit_event(NoticePostedEvent {
+                notice_id,
+                title,
+                body_hash,
+                timestamp,
+            });
+        }
+
+        // Returns posted notices in the order they were posted.
+        //
+        // # Arguments
+        // * `cursor` - Id of the first notice to return
+        // * `limit` - Maximum number of notices to return (capped at MAX_PAGE_SIZE)
+        //
+        // # Returns
+        // Up to `limit` notices starting at `cursor`; empty once past the last notice
+        pub fn get_notices(&self, cursor: u64, limit: u32) -> Vec<Notice> {
+            let end = cursor
+                .saturating_add(limit.min(MAX_PAGE_SIZE) as u64)
+                .min(self.notice_count);
+
+            (cursor..end)
+                .filter_map(|notice_id| self.notices.get(&notice_id).map(|notice| notice.clone()))
+                .collect()
+        }
+
+        // Returns how many badges an account has locked through attributed contributions.
+        //
+        // # Arguments
+        // * `account` - The contributing account
+        //
+        // # Returns
+        // `(admin_badges_locked, upgrade_badges_locked)`; zero for accounts that never contributed
+        pub fn get_contributor_totals(&self, account: ComponentAddress) -> (Decimal, Decimal) {
+            let stats = self.get_contributor_stats(account);
+            (stats.admin_badges_locked, stats.upgrade_badges_locked)
+        }
+
+        // Returns the lock statistics of a single contributing account.
+        //
+        // # Arguments
+        // * `account` - The contributing account
+        //
+        // # Returns
+        // `ContributorStats` with amounts locked, number of attributed locks and first/last lock
+        // timestamps; zeroed (with no timestamps) for accounts that never contributed
+        pub fn get_contributor_stats(&self, account: ComponentAddress) -> ContributorStats {
+            self.contributors
+                .get(&account)
+                .map(|stats| stats.clone())
+                .unwrap_or_default()
+        }
+
+        // Checks whether an account has relinquished at least a given amount of V1 badges.
+        //
+        // Lets other components (e.g. RNS V2 perks) gate features on attributed contributions.
+        // Admin and upgrade badges count towards the same total.
+        //
+        // # Arguments
+        // * `account` - The account to check
+        // * `min_amount` - Minimum cumulative amount of attributed badges
+        //
+        // # Returns
+        // `true` if the account has attributed locks totalling at least `min_amount`
+        pub fn verify_contributor(&self, account: ComponentAddress, min_amount: Decimal) -> bool {
+            let stats = self.get_contributor_stats(account);
+            stats.lock_count > 0
+                && stats.admin_badges_locked + stats.upgrade_badges_locked >= min_amount
+        }
+
+        // Returns the lock record with the given sequence number.
+        //
+        // # Arguments
+        // * `seq` - Sequence number of the lock (the first lock is 0)
+        //
+        // # Returns
+        // The `LockRecord`, or `None` if no lock with that sequence number exists yet
+        pub fn get_lock_record(&self, seq: u64) -> Option<LockRecord> {
+            self.lock_records.get(&seq).map(|record| record.clone())
+        }
+
+        // Returns lock records in sequence order.
+        //
+        // # Arguments
+        // * `start_seq` - Sequence number of the first record to return
+        // * `limit` - Maximum number of records to return (capped at MAX_PAGE_SIZE)
+        //
+        // # Returns
+        // Up to `limit` records starting at `start_seq`; empty once past the last lock
+        pub fn get_lock_history(&self, start_seq: u64, limit: u32) -> Vec<LockRecord> {
+            let end = start_seq
+                .saturating_add(limit.min(MAX_PAGE_SIZE) as u64)
+                .min(self.lock_count);
+
+            (start_seq..end)
+                .filter_map(|seq| self.get_lock_record(seq))
+                .collect()
+        }
+
+        // Returns the points of the progress curve in the order they were recorded.
+        //
+        // A point is recorded whenever a lock reaches a milestone or checkpoint, giving dashboards
+        // a ready-made progress curve directly from ledger state.
+        //
+        // # Arguments
+        // * `start` - Index of the first point to return (the first point is 0)
+        // * `limit` - Maximum number of points to return (capped at MAX_PAGE_SIZE)
+        //
+        // # Returns
+        // Up to `limit` points starting at `start`; empty once past the last point
+        pub fn get_progress_points(&self, start: u64, limit: u32) -> Vec<ProgressPoint> {
+            let end = start
+                .saturating_add(limit.min(MAX_PAGE_SIZE) as u64)
+                .min(self.progress_point_count);
+
+            (start..end)
+                .filter_map(|index| self.progress_points.get(&index).map(|point| point.clone()))
+                .collect()
+        }
+
+        // Returns the lock totals aggregated over an epoch.
+        //
+        // # Arguments
+        // * `epoch` - The epoch to query
+        //
+        // # Returns
+        // `EpochStats` for the epoch; zeroed if nothing was locked during it
+        pub fn get_epoch_stats(&self, epoch: Epoch) -> EpochStats {
+            self.epoch_stats
+                .get(&epoch)
+                .map(|stats| stats.clone())
+                .unwrap_or_default()
+        }
+
+        // Returns whether each badge type's lock target has been met.
+        //
+        // The extended status' `targets_met` reports whether all configured targets have been met.
+        //
+        // # Returns
+        // `(admin_met, upgrade_met)`; each is `true` once the cumulative locked amount of the badge
+        // type has met its target, and always `false` if the badge type has no target
+        pub fn targets_met(&self) -> (bool, bool) {
+            (
+                self.admin_target
+                    .is_some_and(|target| self.admin_lock_stats.total_locked >= target),
+                self.upgrade_target
+                    .is_some_and(|target| self.upgrade_lock_stats.total_locked >= target),
+            )
+        }
+
+        // Returns the percentage of each badge resource's supply held in the vaults.
+        //
+        // Computed against the supply snapshot taken at instantiation, so minting more V1 badges
+        // does not dilute the figure (see `check_supply_integrity`).
+        //
+        // # Returns
+        // `(admin_percent, upgrade_percent)`; `None` for a resource whose supply was not tracked
+        // or was zero at instantiation
+        pub fn percent_of_supply_locked(&self) -> (Option<Decimal>, Option<Decimal>) {
+            (
+                self.percent_locked(V1BadgeType::Admin),
+                self.percent_locked(V1BadgeType::Upgrade),
+            )
+        }
+
+        // Returns how many badges of each resource are still circulating outside the locker.
+        //
+        // Derived from the supply snapshot taken at instantiation minus the vault balances, floored
+        // at zero. A resource whose supply was not tracked at instantiation reports zero.
+        //
+        // # Returns
+        // `(admin_remaining, upgrade_remaining)`
+        pub fn remaining_unlocked_supply(&self) -> (Decimal, Decimal) {
+            (
+                self.remaining_unlocked(V1BadgeType::Admin),
+                self.remaining_unlocked(V1BadgeType::Upgrade),
+            )
+        }
+
+        // Returns whether the entire V1 admin badge supply is locked.
+        //
+        // Intended as a minimal-cost check for other components gating behaviour on relinquishment.
+        //
+        // # Returns
+        // `true` if the vault holds the entire admin badge supply
+        pub fn is_admin_fully_locked(&self) -> bool {
+            self.is_entire_supply_locked(V1BadgeType::Admin)
+        }
+
+        // Returns whether the entire V1 upgrade badge supply is locked.
+        //
+        // # Returns
+        // `true` if the vault holds the entire upgrade badge supply
+        pub fn is_upgrade_fully_locked(&self) -> bool {
+            self.is_entire_supply_locked(V1BadgeType::Upgrade)
+        }
+
+        // Returns the locked balance of a resource, without the caller needing to know which
+        // badge type it is.
+        //
+        // # Arguments
+        // * `resource` - The resource to query
+        //
+        // # Returns
+        // The vault balance for either badge resource or an additional V1 resource; zero for any
+        // other resource
+        pub fn amount_locked_of(&self, resource: ResourceAddress) -> Decimal {
+            match self.badge_type_of(resource) {
+                Some(badge_type) => self.vault(badge_type).amount(),
+                None => self
+                    .other_v1_vaults
+                    .get(&resource)
+                    .map(|vault| vault.amount())
+                    .unwrap_or(Decimal::ZERO),
+            }
+        }
+
+        // Returns every resource the component locks with its locked balance, so clients can list
+        // everything held without hardcoding the badge resources.
+        //
+        // # Returns
+        // `(resource, amount_locked)` pairs, admin badges first, then upgrade badges, then the
+        // additional V1 resources in configuration order
+        pub fn get_all_locked_resources(&self) -> Vec<(ResourceAddress, Decimal)> {
+            [V1BadgeType::Admin, V1BadgeType::Upgrade]
+                .into_iter()
+                .map(|badge_type| self.badge_resource(badge_type))
+                .chain(self.other_v1_resources.iter().copied())
+                .map(|resource| (resource, self.amount_locked_of(resource)))
+                .collect()
+        }
+
+        // Returns the addresses of every vault the component owns: the two badge vaults, the
+        // vaults of the additional V1 resources and a page of the staging vaults.
+        //
+        // Auditors can read the balances directly via substate inspection and, paging through
+        // the staging vaults, confirm that the component owns no other vaults. Staging vaults
+        // are emptied, not removed, once their staged lock is resolved.
+        //
+        // # Arguments
+        // * `staged_start` - Id of the first staged lock whose vault to return
+        // * `staged_limit` - Maximum number of staging vaults to return (capped at MAX_PAGE_SIZE)
+        //
+        // # Returns
+        // The vault addresses, with up to `staged_limit` staging vaults from `staged_start`
+        pub fn get_vault_ids(&self, staged_start: u64, staged_limit: u32) -> V1VaultIds {
+            let staged_end = staged_start
+                .saturating_add(staged_limit.min(MAX_PAGE_SIZE) as u64)
+                .min(self.staged_lock_count);
+
+            V1VaultIds {
+                admin_vault: self.vault_address(V1BadgeType::Admin),
+                upgrade_vault: self.vault_address(V1BadgeType::Upgrade),
+                other_v1_vaults: self
+                    .other_v1_resources
+                    .iter()
+                    .map(|resource| {
+                        let vault = self
+                            .other_v1_vaults
+                            .get(resource)
+                            .expect("Additional V1 vault should exist");
+                        (
+                            *resource,
+                            InternalAddress::new_or_panic(vault.0.as_node_id().0),
+                        )
+                    })
+                    .collect(),
+                staged_lock_count: self.staged_lock_count,
+                staged_vaults: (staged_start..staged_end)
+                    .map(|staged_lock_id| {
+                        let vault = self
+                            .staged_vaults
+                            .get(&staged_lock_id)
+                            .expect("Staged vault should exist");
+                        (
+                            staged_lock_id,
+                            InternalAddress::new_or_panic(vault.0.as_node_id().0),
+                        )
+                    })
+                    .collect(),
+            }
+        }
+
+        // Returns the local ids of the locked badges of a non-fungible badge resource, so anyone
+        // can verify exactly which badges are out of circulation.
+        //
+        // Reads the whole vault, so it is intended to be called via transaction preview.
+        //
+        // # Arguments
+        // * `badge_type` - The badge type to query
+        //
+        // # Returns
+        // The locked local ids; empty if the badge resource is fungible
+        pub fn get_locked_nft_ids(&self, badge_type: V1BadgeType) -> Vec<NonFungibleLocalId> {
+            self.non_fungible_vault(badge_type)
+                .map(|vault| vault.non_fungible_local_ids(u32::MAX).into_iter().collect())
+                .unwrap_or_default()
+        }
+
+        // Returns the non-fungible data of a locked badge, so historical badge metadata remains
+        // queryable after the badge is vaulted.
+        //
+        // # Arguments
+        // * `badge_type` - The badge type of the badge
+        // * `id` - Local id of the badge
+        //
+        // # Returns
+        // The badge's data as a generic SBOR value; `None` if no badge with the id is locked
+        pub fn get_locked_nft_data(
+            &self,
+            badge_type: V1BadgeType,
+            id: NonFungibleLocalId,
+        ) -> Option<ScryptoValue> {
+            self.non_fungible_vault(badge_type)
+                .filter(|vault| vault.contains_non_fungible(&id))
+                .map(|vault| {
+                    let data: RawNonFungibleData =
+                        vault.resource_manager().get_non_fungible_data(&id);
+                    data.0
+                })
+        }
+
+        // Returns whether a non-fungible badge has been relinquished.
+        //
+        // # Arguments
+        // * `badge_type` - The badge type of the badge
+        // * `id` - Local id of the badge
+        //
+        // # Returns
+        // `true` if the badge is locked; always `false` for fungible badge resources
+        pub fn contains_nft(&self, badge_type: V1BadgeType, id: NonFungibleLocalId) -> bool {
+            self.non_fungible_vault(badge_type)
+                .is_some_and(|vault| vault.contains_non_fungible(&id))
+        }
+
+        // Returns the top contributors by total badges locked (admin + upgrade).
+        //
+        // # Arguments
+        // * `n` - Number of entries to return (at most MAX_LEADERBOARD_SIZE are tracked)
+        //
+        // # Returns
+        // Up to `n` `(account, total_locked)` pairs, largest first
+        pub fn top_contributors(&self, n: u32) -> Vec<(ComponentAddress, Decimal)> {
+            self.leaderboard.iter().take(n as usize).cloned().collect()
+        }
+
+        // Returns a deterministic digest of all attributed contributions.
+        //
+        // The digest is the blake2b-256 hash of the Scrypto SBOR encoding of a
+        // `Vec<(ComponentAddress, Decimal, Decimal)>` of `(account, admin_locked, upgrade_locked)`
+        // entries, sorted by account address bytes. Off-chain snapshots can recompute it to
+        // verify they match on-ledger state. Cost grows with the number of contributors, so it is
+        // intended to be called via transaction preview.
+        //
+        // # Returns
+        // The digest of the contribution map
+        pub fn get_contribution_digest(&self) -> Hash {
+            let mut entries: Vec<(ComponentAddress, Decimal, Decimal)> = (0..self
+                .unique_contributors)
+                .filter_map(|index| self.contributor_index.get(&index).map(|account| *account))
+                .map(|account| {
+                    let stats = self.get_contributor_stats(account);
+                    (
+                        account,
+                        stats.admin_badges_locked,
+                        stats.upgrade_badges_locked,
+                    )
+                })
+                .collect();
+            entries.sort_by_key(|(account, _, _)| account.as_node_id().0);
+
+            hash(scrypto_encode(&entries).expect("Contribution entries should encode"))
+        }
+
+        // Returns the resource address of the receipt NFTs minted on attributed locks.
+        //
+        // # Returns
+        // The receipt resource address
+        pub fn get_receipt_resource(&self) -> ResourceAddress {
+            self.receipt_resource
+        }
 
-            let locked_count = v1_upgrade_badges.amount();
+        // Returns the resource address of the fungible RELINQUISHED token returned by lock methods.
+        //
+        // # Returns
+        // The RELINQUISHED resource address
+        pub fn get_relinquished_resource(&self) -> ResourceAddress {
+            self.relinquished_resource
+        }
 
-            self.v1_upgrade_badges_vault.put(v1_upgrade_badges);
+        // Returns the resource address of the commemorative NFTs claimable by contributors.
+        //
+        // # Returns
+        // The commemorative resource address
+        pub fn get_commemorative_resource(&self) -> ResourceAddress {
+            self.commemorative_resource
+        }
 
-            Runtime::emit_event(V1UpgradeBadgeLockedEvent {
-                badges_locked: locked_count,
-                total_locked_now: self.v1_upgrade_badges_vault.amount(),
-                timestamp: Clock::current_time_rounded_to_minutes(),
-            });
+        // Returns the account locker holding receipts that could not be deposited directly.
+        //
+        // Contributors claim held receipts by calling `claim` on the locker with a proof of their
+        // account's owner badge.
+        //
+        // # Returns
+        // The receipt locker's component address
+        pub fn get_receipt_locker(&self) -> ComponentAddress {
+            self.receipt_locker.address()
         }
 
         // Returns the current lock status showing how many V1 badges are locked.
@@ -153,5 +2493,1100 @@ mod rns_v1_badge_lockers {
                 upgrade_badge_resource: self.v1_upgrade_badge_resource,
             }
         }
+
+        // Returns the extended lock status, including contributor statistics.
+        //
+        // Team figures only cover locks attributed to a team account with its owner badge proof;
+        // community figures cover every other lock, including unattributed locks and locks made
+        // for a team account by someone else.
+        //
+        // # Returns
+        // `V1LockStatusV2` with everything in `V1LockStatus` plus contributor statistics
+        pub fn get_lock_status_v2(&self) -> V1LockStatusV2 {
+            let admin_badges_locked = self.v1_admin_badges_vault.amount();
+            let upgrade_badges_locked = self.v1_upgrade_badges_vault.amount();
+
+            V1LockStatusV2 {
+                admin_badges_locked,
+                upgrade_badges_locked,
+                admin_badge_resource: self.v1_admin_badge_resource,
+                upgrade_badge_resource: self.v1_upgrade_badge_resource,
+                admin_supply_at_instantiation: self.admin_supply_at_instantiation,
+                upgrade_supply_at_instantiation: self.upgrade_supply_at_instantiation,
+                supply_anomaly_detected: self.supply_anomaly_detected,
+                admin_badge_risks: self.admin_badge_risks,
+                upgrade_badge_risks: self.upgrade_badge_risks,
+                admin_percent_locked: self.percent_locked(V1BadgeType::Admin),
+                upgrade_percent_locked: self.percent_locked(V1BadgeType::Upgrade),
+                lock_count: self.lock_count,
+                unique_contributors: self.unique_contributors,
+                team_admin_badges_locked: self.team_admin_badges_locked,
+                team_upgrade_badges_locked: self.team_upgrade_badges_locked,
+                community_admin_badges_locked: admin_badges_locked - self.team_admin_badges_locked,
+                community_upgrade_badges_locked: upgrade_badges_locked
+                    - self.team_upgrade_badges_locked,
+                admin_lock_stats: self.admin_lock_stats.clone(),
+                upgrade_lock_stats: self.upgrade_lock_stats.clone(),
+                completed_at: self.completed_at,
+                fully_relinquished_artifact: self.fully_relinquished_artifact,
+                final_report: self.final_report,
+                entire_supply_locked_at: self.entire_supply_locked_at,
+                targets_met: self.targets_met_by(
+                    self.admin_lock_stats.total_locked,
+                    self.upgrade_lock_stats.total_locked,
+                ),
+                attestation: self.attestation.clone(),
+                campaign_start_epoch: self.campaign_start_epoch,
+                campaign_end_epoch: self.campaign_end_epoch,
+                epochs_remaining: self.campaign_end_epoch.map(|end_epoch| {
+                    end_epoch
+                        .number()
+                        .saturating_sub(Runtime::current_epoch().number())
+                }),
+                campaign_window_open: self.is_campaign_window_open(),
+            }
+        }
+
+        // Returns the component's provenance and configured resources in one call, so explorers
+        // need not make several gateway requests.
+        //
+        // # Returns
+        // `V1ComponentInfo` with the package, blueprint, instantiation time and resources
+        pub fn get_component_info(&self) -> V1ComponentInfo {
+            let blueprint_id = V1AuthRelinquishment::blueprint_id();
+
+            V1ComponentInfo {
+                package_address: blueprint_id.package_address,
+                blueprint_name: blueprint_id.blueprint_name,
+                component_address: Runtime::global_address(),
+                instantiated_epoch: self.instantiated_epoch,
+                instantiated_at: self.instantiated_at,
+                admin_badge_resource: self.v1_admin_badge_resource,
+                upgrade_badge_resource: self.v1_upgrade_badge_resource,
+                receipt_resource: self.receipt_resource,
+                relinquished_resource: self.relinquished_resource,
+                commemorative_resource: self.commemorative_resource,
+                receipt_locker: self.get_receipt_locker(),
+            }
+        }
+
+        // Returns what the extended lock status and milestone state would be after a hypothetical
+        // unattributed lock, so wallets can show the post-lock state before the user signs.
+        //
+        // If the lock would complete the campaign, `completed_at` is set to the current time but
+        // `fully_relinquished_artifact` stays `None`, as the artifact does not exist yet.
+        //
+        // # Arguments
+        // * `badge_type` - The badge type of the hypothetical lock
+        // * `amount` - The amount of the hypothetical lock
+        //
+        // # Returns
+        // `V1LockPreview` with the status after the lock, the milestones it would reach and the
+        // next milestone
+        //
+        // # Panics
+        // * If the amount is not positive
+        // * If whole units are enforced and the amount is fractional
+        // * If the campaign window has not opened, or has closed and late locks are refused
+        pub fn preview_lock(&self, badge_type: V1BadgeType, amount: Decimal) -> V1LockPreview {
+            assert!(
+                amount.is_positive(),
+                "Cannot lock an empty bucket of V1 {} badges",
+                badge_type.label()
+            );
+            assert!(
+                !self.whole_units_only || is_whole_amount(amount),
+                "Only whole V1 {} badges can be locked, received {}",
+                badge_type.label(),
+                amount
+            );
+            self.assert_accepting_locks();
+
+            let now = Clock::current_time_rounded_to_seconds();
+            let mut status = self.get_lock_status_v2();
+            match badge_type {
+                V1BadgeType::Admin => {
+                    status.admin_badges_locked += amount;
+                    status.community_admin_badges_locked += amount;
+                    status.admin_lock_stats.record(amount, now);
+                }
+                V1BadgeType::Upgrade => {
+                    status.upgrade_badges_locked += amount;
+                    status.community_upgrade_badges_locked += amount;
+                    status.upgrade_lock_stats.record(amount, now);
+                }
+            }
+            status.admin_percent_locked =
+                self.percent_of_supply(V1BadgeType::Admin, status.admin_badges_locked);
+            status.upgrade_percent_locked =
+                self.percent_of_supply(V1BadgeType::Upgrade, status.upgrade_badges_locked);
+            status.lock_count += 1;
+
+            let admin_total = status.admin_lock_stats.total_locked;
+            let upgrade_total = status.upgrade_lock_stats.total_locked;
+            if status.entire_supply_locked_at.is_none()
+                && self.is_entire_supply(V1BadgeType::Admin, status.admin_badges_locked)
+                && self.is_entire_supply(V1BadgeType::Upgrade, status.upgrade_badges_locked)
+            {
+                status.entire_supply_locked_at = Some(now);
+            }
+            if status.completed_at.is_none() && self.is_complete_at(admin_total, upgrade_total) {
+                status.completed_at = Some(now);
+            }
+            status.targets_met = self.targets_met_by(admin_total, upgrade_total);
+
+            let (milestones, total_locked) = match badge_type {
+                V1BadgeType::Admin => (&self.admin_milestones, admin_total),
+                V1BadgeType::Upgrade => (&self.upgrade_milestones, upgrade_total),
+            };
+            let previous_total = total_locked - amount;
+
+            V1LockPreview {
+                status,
+                milestones_reached: milestones
+                    .iter()
+                    .copied()
+                    .filter(|milestone| previous_total < *milestone && *milestone <= total_locked)
+                    .collect(),
+                next_milestone: milestones
+                    .iter()
+                    .copied()
+                    .find(|milestone| *milestone > total_locked),
+            }
+        }
+
+        // Returns a short human-readable summary of the locked badges, for wallets rendering
+        // transaction previews, e.g. "5,000/10,000 admin badges (50%) and 3/3 upgrade badges
+        // (100%) permanently locked".
+        //
+        // # Returns
+        // The summary; the supply and percentage are omitted for resources not tracking supply
+        pub fn describe(&self) -> String {
+            format!(
+                "{} and {} permanently locked",
+                self.describe_badges(V1BadgeType::Admin),
+                self.describe_badges(V1BadgeType::Upgrade)
+            )
+        }
+
+        // Returns the lock status serialized as JSON, for off-chain consumers that cannot decode
+        // SBOR and call the method via transaction preview.
+        //
+        // Decimals are encoded as strings to preserve precision, addresses as Bech32m and instants
+        // as seconds since the unix epoch.
+        //
+        // # Returns
+        // A JSON object with the main fields of `V1LockStatusV2`
+        pub fn get_status_json(&self) -> String {
+            let status = self.get_lock_status_v2();
+
+            format!(
+                concat!(
+                    "{{",
+                    "\"admin_badges_locked\":{},",
+                    "\"upgrade_badges_locked\":{},",
+                    "\"admin_badge_resource\":\"{}\",",
+                    "\"upgrade_badge_resource\":\"{}\",",
+                    "\"admin_percent_locked\":{},",
+                    "\"upgrade_percent_locked\":{},",
+                    "\"lock_count\":{},",
+                    "\"unique_contributors\":{},",
+                    "\"completed_at\":{},",
+                    "\"entire_supply_locked_at\":{},",
+                    "\"targets_met\":{}",
+                    "}}"
+                ),
+                json_decimal(Some(status.admin_badges_locked)),
+                json_decimal(Some(status.upgrade_badges_locked)),
+                Runtime::bech32_encode_address(status.admin_badge_resource),
+                Runtime::bech32_encode_address(status.upgrade_badge_resource),
+                json_decimal(status.admin_percent_locked),
+                json_decimal(status.upgrade_percent_locked),
+                status.lock_count,
+                status.unique_contributors,
+                json_instant(status.completed_at),
+                json_instant(status.entire_supply_locked_at),
+                status.targets_met
+            )
+        }
+
+        // Locks the bucket unless `lock` would reject it, in which case it is returned
+        fn try_lock(&mut self, badge_type: V1BadgeType, badges: Bucket) -> Result<Bucket, Bucket> {
+            let reason = if badges.resource_address() != self.badge_resource(badge_type) {
+                Some(V1RejectionReason::WrongResource)
+            } else if badges.is_empty() {
+                Some(V1RejectionReason::EmptyBucket)
+            } else if self.whole_units_only && !is_whole_amount(badges.amount()) {
+                Some(V1RejectionReason::FractionalAmount)
+            } else if !self.is_accepting_locks() {
+                Some(V1RejectionReason::CampaignWindowClosed)
+            } else {
+                None
+            };
+
+            match reason {
+                Some(reason) => {
+                    Runtime::emit_event(DepositRejectedEvent {
+                        badge_type,
+                        resource_address: badges.resource_address(),
+                        amount: badges.amount(),
+                        reason,
+                        timestamp: Clock::current_time_rounded_to_seconds(),
+                    });
+                    Err(badges)
+                }
+                None => Ok(self.lock_with_proof(badge_type, badges, None)),
+            }
+        }
+
+        // Locks a bucket attributed by an optional account proof, minting or updating the
+        // contributor's receipt unless the campaign had already completed. Returns the
+        // RELINQUISHED tokens for the lock.
+        fn lock_with_proof(
+            &mut self,
+            badge_type: V1BadgeType,
+            badges: Bucket,
+            account_proof: Option<Proof>,
+        ) -> Bucket {
+            let contributor = account_proof.map(Self::contributor_from_proof);
+            self.lock_attributed(badge_type, badges, contributor)
+        }
+
+        // Locks badges for an optional contributor, issuing their receipt while the campaign is
+        // open, and returns the RELINQUISHED tokens
+        fn lock_attributed(
+            &mut self,
+            badge_type: V1BadgeType,
+            badges: Bucket,
+            contributor: Option<ComponentAddress>,
+        ) -> Bucket {
+            let campaign_open = self.completed_at.is_none();
+            let record = self.lock(badge_type, badges, contributor);
+
+            // Only proof-attributed locks count as the team's, so locking badges for a team
+            // account cannot inflate the team figures
+            if contributor.is_some_and(|account| self.team_accounts.contains(&account)) {
+                match badge_type {
+                    V1BadgeType::Admin => self.team_admin_badges_locked += record.amount,
+                    V1BadgeType::Upgrade => self.team_upgrade_badges_locked += record.amount,
+                }
+            }
+
+            // The lock completing the campaign still receives its receipt
+            if let Some(account) = contributor.filter(|_| campaign_open) {
+                self.issue_receipt(account, &record);
+            }
+
+            self.mint_relinquished(record.amount)
+        }
+
+        // Updates the contributor's receipt for the record's badge type, minting and delivering
+        // it on their first proof-attributed lock of that type. Receipts the account rejects are
+        // kept in the receipt locker for the contributor to claim.
+        fn issue_receipt(&mut self, account: ComponentAddress, record: &LockRecord) {
+            let stats = self.get_contributor_stats(account);
+            let total_locked = match record.badge_type {
+                V1BadgeType::Admin => stats.admin_badges_locked,
+                V1BadgeType::Upgrade => stats.upgrade_badges_locked,
+            };
+
+            let manager = ResourceManager::from(self.receipt_resource);
+            let receipt_id = receipt_nft_id(account, record.badge_type);
+            if manager.non_fungible_exists(&receipt_id) {
+                manager.update_non_fungible_data(&receipt_id, "total_locked", total_locked);
+                manager.update_non_fungible_data(
+                    &receipt_id,
+                    "last_contribution",
+                    record.timestamp,
+                );
+                manager.update_non_fungible_data(&receipt_id, "last_seq", record.seq);
+                manager.update_non_fungible_data(&receipt_id, "last_epoch", record.epoch);
+                manager.update_non_fungible_data(&receipt_id, "last_amount", record.amount);
+            } else {
+                let receipt = manager.mint_non_fungible(
+                    &receipt_id,
+                    V1RelinquishmentReceipt {
+                        contributor: account,
+                        badge_type: record.badge_type,
+                        first_contribution: record.timestamp,
+                        total_locked,
+                        last_contribution: record.timestamp,
+                        last_seq: record.seq,
+                        last_epoch: record.epoch,
+                        last_amount: record.amount,
+                    },
+                );
+                self.receipt_locker
+                    .store(Global::<Account>::from(account), receipt, true);
+            }
+        }
+
+        // Validates the memo and locks the badges without attribution, storing the memo with the
+        // lock record and event. Returns the RELINQUISHED tokens for the lock.
+        fn lock_with_memo(
+            &mut self,
+            badge_type: V1BadgeType,
+            badges: Bucket,
+            memo: String,
+        ) -> Bucket {
+            assert!(
+                !memo.is_empty() && memo.len() <= MAX_MEMO_LENGTH,
+                "Memo must be between 1 and {} bytes",
+                MAX_MEMO_LENGTH
+            );
+
+            let record = self.lock_inner(badge_type, badges, None, Some(memo), true);
+            self.mint_relinquished(record.amount)
+        }
+
+        // Mints RELINQUISHED tokens for `amount` locked badges
+        fn mint_relinquished(&self, amount: Decimal) -> Bucket {
+            ResourceManager::from(self.relinquished_resource).mint(amount)
+        }
+
+        // Emits a `StatusSnapshotEvent` with the current totals.
+        //
+        // Anyone can call this, e.g. periodically, so indexers that only consume events get
+        // canonical snapshots without querying state.
+        pub fn emit_status_snapshot(&self) {
+            Runtime::emit_event(StatusSnapshotEvent {
+                admin_badges_locked: self.v1_admin_badges_vault.amount(),
+                upgrade_badges_locked: self.v1_upgrade_badges_vault.amount(),
+                admin_percent_locked: self.percent_locked(V1BadgeType::Admin),
+                upgrade_percent_locked: self.percent_locked(V1BadgeType::Upgrade),
+                unique_contributors: self.unique_contributors,
+                lock_count: self.lock_count,
+                admin_last_locked_at: self.admin_lock_stats.last_locked_at,
+                upgrade_last_locked_at: self.upgrade_lock_stats.last_locked_at,
+                timestamp: Clock::current_time_rounded_to_seconds(),
+            });
+        }
+
+        // Checks that the badge resources' total supplies are unchanged since instantiation.
+        //
+        // Anyone can call this. A `SupplyAnomalyEvent` is emitted for every resource whose supply
+        // changed, and the status' `supply_anomaly_detected` flag is set for good, so minting or
+        // burning V1 badges cannot silently skew the percentage locked.
+        //
+        // # Returns
+        // `true` if both supplies are unchanged
+        pub fn check_supply_integrity(&mut self) -> bool {
+            let mut intact = true;
+            for (badge_type, supply_at_instantiation) in [
+                (V1BadgeType::Admin, self.admin_supply_at_instantiation),
+                (V1BadgeType::Upgrade, self.upgrade_supply_at_instantiation),
+            ] {
+                let resource_address = self.badge_resource(badge_type);
+                let current_supply = ResourceManager::from(resource_address).total_supply();
+                if current_supply != supply_at_instantiation {
+                    intact = false;
+                    self.supply_anomaly_detected = true;
+                    Runtime::emit_event(SupplyAnomalyEvent {
+                        badge_type,
+                        resource_address,
+                        supply_at_instantiation,
+                        current_supply,
+                        timestamp: Clock::current_time_rounded_to_seconds(),
+                    });
+                }
+            }
+
+            intact
+        }
+
+        // Completes the campaign on presentation of the V2-launch attestation, permanently
+        // sealing receipt issuance and emitting `RelinquishmentSealedEvent` (and minting the
+        // completion artifact if there is a completion condition), regardless of the amounts
+        // locked. Badges remain locked forever and later locks are still accepted.
+        //
+        // # Arguments
+        // * `attestation_proof` - Proof of the configured attestation non-fungible
+        //
+        // # Panics
+        // * If no attestation is configured
+        // * If the proof is not of the attestation non-fungible
+        // * If the campaign is already complete
+        pub fn finalize_with_attestation(&mut self, attestation_proof: Proof) {
+            let attestation = self
+                .attestation
+                .clone()
+                .expect("No attestation is configured");
+            let attestation_proof = attestation_proof.check_with_message(
+                attestation.resource_address(),
+                "Proof is not of the attestation resource",
+            );
+            assert!(
+                attestation_proof
+                    .as_non_fungible()
+                    .non_fungible_local_ids()
+                    .contains(attestation.local_id()),
+                "Proof is not of the attestation non-fungible {}",
+                attestation.local_id()
+            );
+            assert!(
+                self.completed_at.is_none(),
+                "The campaign is already complete"
+            );
+
+            self.complete(Clock::current_time_rounded_to_seconds(), None);
+        }
+
+        // Mints the immutable final report of the completed campaign and sends it to the
+        // archive account of the completion condition (via the receipt locker, should the
+        // account reject it).
+        //
+        // Anyone can call this once. The report records the final totals, the number of unique
+        // contributors and locks, the completion time and the contribution digest as history
+        // root. It is minted with a fixed supply of one, so no further report can ever exist.
+        //
+        // # Panics
+        // * If the campaign is not complete
+        // * If no completion condition, and so no archive account, is configured
+        // * If the final report has already been minted
+        pub fn mint_final_report(&mut self) {
+            let completed_at = self
+                .completed_at
+                .expect("The final report can only be minted once the campaign is complete");
+            let archive_account = self
+                .completion_condition
+                .as_ref()
+                .map(|condition| condition.archive_account)
+                .expect("No archive account is configured");
+            assert!(
+                self.final_report.is_none(),
+                "The final report has already been minted"
+            );
+
+            let history_root = self.get_contribution_digest();
+            let reported_at = Clock::current_time_rounded_to_seconds();
+            let report = ResourceBuilder::new_ruid_non_fungible::<V1FinalReport>(OwnerRole::None)
+                .metadata(metadata! {
+                    init {
+                        "name" => "V1 Relinquishment Final Report", locked;
+                        "description" => "Final totals of the RNS V1 badge relinquishment.", locked;
+                        "tags" => ["rns", "v1", "report"], locked;
+                    }
+                })
+                .mint_initial_supply([V1FinalReport {
+                    completed_at,
+                    admin_badges_locked: self.admin_lock_stats.total_locked,
+                    upgrade_badges_locked: self.upgrade_lock_stats.total_locked,
+                    unique_contributors: self.unique_contributors,
+                    lock_count: self.lock_count,
+                    history_root,
+                    reported_at,
+                }]);
+            let report_resource = report.resource_address();
+            self.final_report = Some(report_resource);
+
+            self.receipt_locker.store(
+                Global::<Account>::from(archive_account),
+                report.into(),
+                true,
+            );
+
+            Runtime::emit_event(FinalReportMintedEvent {
+                report_resource,
+                archive_account,
+                history_root,
+                timestamp: reported_at,
+            });
+        }
+
+        // Panics unless the bucket holds a positive (and, if enforced, whole) amount of the badge
+        // type's resource and the campaign accepts locks
+        fn assert_lockable(&self, badge_type: V1BadgeType, badges: &Bucket) {
+            let expected_resource = self.badge_resource(badge_type);
+            assert_eq!(
+                badges.resource_address(),
+                expected_resource,
+                "Invalid V1 {} badge resource. Expected {:?}, received {:?}",
+                badge_type.label(),
+                expected_resource,
+                badges.resource_address()
+            );
+
+            // Empty buckets would emit misleading lock events for zero badges
+            let amount = badges.amount();
+            assert!(
+                amount.is_positive(),
+                "Cannot lock an empty bucket of V1 {} badges",
+                badge_type.label()
+            );
+            assert!(
+                !self.whole_units_only || is_whole_amount(amount),
+                "Only whole V1 {} badges can be locked, received {}",
+                badge_type.label(),
+                amount
+            );
+            self.assert_accepting_locks();
+        }
+
+        fn assert_accepting_locks(&self) {
+            assert!(
+                self.is_accepting_locks(),
+                "The campaign window is closed at epoch {}",
+                Runtime::current_epoch().number()
+            );
+        }
+
+        // Marks a pending staged lock as resolved and empties its vault, checking the depositor
+        // if one is given. Returns the staged lock as it was and its badges.
+        fn resolve_staged_lock(
+            &mut self,
+            staged_lock_id: u64,
+            depositor: Option<ComponentAddress>,
+            status: V1StagedLockStatus,
+        ) -> (V1StagedLock, Bucket) {
+            let staged_lock = self
+                .get_staged_lock(staged_lock_id)
+                .unwrap_or_else(|| panic!("No lock was staged under id {}", staged_lock_id));
+            assert_eq!(
+                staged_lock.status,
+                V1StagedLockStatus::Pending,
+                "Staged lock {} is no longer pending",
+                staged_lock_id
+            );
+            if let Some(depositor) = depositor {
+                assert_eq!(
+                    depositor, staged_lock.depositor,
+                    "Only the depositor can resolve staged lock {}",
+                    staged_lock_id
+                );
+            }
+
+            self.staged_locks
+                .get_mut(&staged_lock_id)
+                .expect("Staged lock should exist")
+                .status = status;
+            let badges = self
+                .staged_vaults
+                .get_mut(&staged_lock_id)
+                .expect("Staged vault should exist")
+                .take_all();
+
+            (staged_lock, badges)
+        }
+
+        // Validates and vaults a bucket of V1 badges, records attribution and emits the lock event.
+        // Returns the record of the lock.
+        fn lock(
+            &mut self,
+            badge_type: V1BadgeType,
+            badges: Bucket,
+            contributor: Option<ComponentAddress>,
+        ) -> LockRecord {
+            self.lock_inner(badge_type, badges, contributor, None, true)
+        }
+
+        // `lock`, leaving the lock event to the caller unless `emit_lock_event` is set
+        fn lock_inner(
+            &mut self,
+            badge_type: V1BadgeType,
+            badges: Bucket,
+            contributor: Option<ComponentAddress>,
+            memo: Option<String>,
+            emit_lock_event: bool,
+        ) -> LockRecord {
+            self.assert_lockable(badge_type, &badges);
+            let expected_resource = self.badge_resource(badge_type);
+            let locked_count = badges.amount();
+
+            self.vault_mut(badge_type).put(badges);
+
+            let timestamp = Clock::current_time_rounded_to_seconds();
+            if let Some(account) = contributor {
+                self.record_contribution(badge_type, account, locked_count, timestamp);
+            }
+
+            match badge_type {
+                V1BadgeType::Admin => &mut self.admin_lock_stats,
+                V1BadgeType::Upgrade => &mut self.upgrade_lock_stats,
+            }
+            .record(locked_count, timestamp);
+
+            let seq = self.lock_count;
+            let epoch = Runtime::current_epoch();
+            let record = LockRecord {
+                seq,
+                badge_type,
+                amount: locked_count,
+                timestamp,
+                epoch,
+                contributor,
+                memo,
+                late: self.has_campaign_ended(),
+            };
+            self.lock_records.insert(seq, record.clone());
+            self.lock_count += 1;
+
+            let mut epoch_stats = self.get_epoch_stats(epoch);
+            match badge_type {
+                V1BadgeType::Admin => epoch_stats.admin_badges_locked += locked_count,
+                V1BadgeType::Upgrade => epoch_stats.upgrade_badges_locked += locked_count,
+            }
+            epoch_stats.lock_count += 1;
+            self.epoch_stats.insert(epoch, epoch_stats);
+
+            if emit_lock_event {
+                let total_locked_now = self.vault(badge_type).amount();
+                match badge_type {
+                    V1BadgeType::Admin => Runtime::emit_event(V1AdminBadgesLockedEvent {
+                        resource_address: expected_resource,
+                        badge_type,
+                        badges_locked: locked_count,
+                        total_locked_now,
+                        contributor,
+                        timestamp,
+                        epoch,
+                        sequence: seq,
+                        memo: record.memo.clone(),
+                        late: record.late,
+                    }),
+                    V1BadgeType::Upgrade => Runtime::emit_event(V1UpgradeBadgeLockedEvent {
+                        resource_address: expected_resource,
+                        badge_type,
+                        badges_locked: locked_count,
+                        total_locked_now,
+                        contributor,
+                        timestamp,
+                        epoch,
+                        sequence: seq,
+                        memo: record.memo.clone(),
+                        late: record.late,
+                    }),
+                }
+            }
+
+            // Totals only grow, so each milestone is crossed exactly once
+            let (milestones, total_locked) = match badge_type {
+                V1BadgeType::Admin => (&self.admin_milestones, self.admin_lock_stats.total_locked),
+                V1BadgeType::Upgrade => (
+                    &self.upgrade_milestones,
+                    self.upgrade_lock_stats.total_locked,
+                ),
+            };
+            let previous_total = total_locked - locked_count;
+            let mut progress_point_reached = false;
+            for milestone in milestones
+                .iter()
+                .filter(|milestone| previous_total < **milestone && **milestone <= total_locked)
+            {
+                progress_point_reached = true;
+                Runtime::emit_event(MilestoneReachedEvent {
+                    resource_address: expected_resource,
+                    badge_type,
+                    milestone: *milestone,
+                    total_locked,
+                    sequence: seq,
+                    timestamp,
+                });
+            }
+
+            // A single event reports the highest checkpoint reached, however many were crossed
+            let checkpoint_interval = match badge_type {
+                V1BadgeType::Admin => self.admin_checkpoint_interval,
+                V1BadgeType::Upgrade => self.upgrade_checkpoint_interval,
+            };
+            if let Some(interval) = checkpoint_interval {
+                let checkpoint = last_checkpoint(total_locked, interval);
+                if checkpoint > last_checkpoint(previous_total, interval) {
+                    progress_point_reached = true;
+                    Runtime::emit_event(CheckpointEvent {
+                        resource_address: expected_resource,
+                        badge_type,
+                        checkpoint,
+                        total_locked,
+                        sequence: seq,
+                        timestamp,
+                    });
+                }
+            }
+
+            if progress_point_reached {
+                self.progress_points.insert(
+                    self.progress_point_count,
+                    ProgressPoint {
+                        badge_type,
+                        timestamp,
+                        total_locked,
+                        sequence: seq,
+                    },
+                );
+                self.progress_point_count += 1;
+            }
+
+            let target = match badge_type {
+                V1BadgeType::Admin => self.admin_target,
+                V1BadgeType::Upgrade => self.upgrade_target,
+            };
+            if let Some(target) =
+                target.filter(|target| previous_total < *target && *target <= total_locked)
+            {
+                Runtime::emit_event(TargetReachedEvent {
+                    resource_address: expected_resource,
+                    badge_type,
+                    target,
+                    total_locked,
+                    sequence: seq,
+                    timestamp,
+                });
+            }
+
+            if self.entire_supply_locked_at.is_none()
+                && self.is_entire_supply_locked(V1BadgeType::Admin)
+                && self.is_entire_supply_locked(V1BadgeType::Upgrade)
+            {
+                self.entire_supply_locked_at = Some(timestamp);
+                Runtime::emit_event(V1FullyRelinquishedEvent {
+                    admin_badges_locked: self.v1_admin_badges_vault.amount(),
+                    upgrade_badges_locked: self.v1_upgrade_badges_vault.amount(),
+                    sequence: seq,
+                    timestamp,
+                });
+            }
+
+            if self.completed_at.is_none() && self.is_complete() {
+                self.complete(record.timestamp, Some(record.seq));
+            }
+
+            record
+        }
+
+        // Percentage of the supply snapshot held in the vault, if the snapshot is non-zero
+        fn percent_locked(&self, badge_type: V1BadgeType) -> Option<Decimal> {
+            self.percent_of_supply(badge_type, self.vault(badge_type).amount())
+        }
+
+        // Percentage of the supply snapshot that `amount` represents, if the snapshot is non-zero.
+        // The snapshot is the fixed denominator of all progress figures, so minting more V1 badges
+        // does not dilute them.
+        fn percent_of_supply(&self, badge_type: V1BadgeType, amount: Decimal) -> Option<Decimal> {
+            self.supply_at_instantiation(badge_type)
+                .filter(|supply| supply.is_positive())
+                .map(|supply| amount * dec!(100) / supply)
+        }
+
+        fn supply_at_instantiation(&self, badge_type: V1BadgeType) -> Option<Decimal> {
+            match badge_type {
+                V1BadgeType::Admin => self.admin_supply_at_instantiation,
+                V1BadgeType::Upgrade => self.upgrade_supply_at_instantiation,
+            }
+        }
+
+        fn remaining_unlocked(&self, badge_type: V1BadgeType) -> Decimal {
+            let supply = self
+                .supply_at_instantiation(badge_type)
+                .unwrap_or(Decimal::ZERO);
+            (supply - self.vault(badge_type).amount()).max(Decimal::ZERO)
+        }
+
+        // Summary of a badge type's locked amount against its supply snapshot, for `describe`
+        fn describe_badges(&self, badge_type: V1BadgeType) -> String {
+            let locked = format_amount(self.vault(badge_type).amount());
+            let total_supply = self.supply_at_instantiation(badge_type);
+            let percent = self
+                .percent_locked(badge_type)
+                .and_then(|percent| percent.checked_round(2, RoundingMode::ToZero));
+
+            match (total_supply, percent) {
+                (Some(total_supply), Some(percent)) => format!(
+                    "{}/{} {} badges ({}%)",
+                    locked,
+                    format_amount(total_supply),
+                    badge_type.label(),
+                    percent
+                ),
+                _ => format!("{} {} badges", locked, badge_type.label()),
+            }
+        }
+
+        // Whether the current epoch is within the configured campaign start and end epochs
+        fn is_campaign_window_open(&self) -> bool {
+            self.has_campaign_started() && !self.has_campaign_ended()
+        }
+
+        fn has_campaign_started(&self) -> bool {
+            !self
+                .campaign_start_epoch
+                .is_some_and(|start_epoch| Runtime::current_epoch() < start_epoch)
+        }
+
+        fn has_campaign_ended(&self) -> bool {
+            self.campaign_end_epoch
+                .is_some_and(|end_epoch| Runtime::current_epoch() >= end_epoch)
+        }
+
+        // Whether locks are accepted: within the campaign window, or after it under the
+        // `AcceptLate` policy
+        fn is_accepting_locks(&self) -> bool {
+            self.has_campaign_started()
+                && (!self.has_campaign_ended()
+                    || self.late_lock_policy == V1LateLockPolicy::AcceptLate)
+        }
+
+        // Whether the lock targets are met by the given cumulative locked amounts
+        fn targets_met_by(&self, admin_total: Decimal, upgrade_total: Decimal) -> bool {
+            let admin_met = self.admin_target.map(|target| admin_total >= target);
+            let upgrade_met = self.upgrade_target.map(|target| upgrade_total >= target);
+
+            match (admin_met, upgrade_met) {
+                (None, None) => false,
+                (admin_met, upgrade_met) => {
+                    admin_met.unwrap_or(true) && upgrade_met.unwrap_or(true)
+                }
+            }
+        }
+
+        // Whether the vault holds the entire supply snapshot of the badge resource. Always false
+        // for resources that did not track their total supply at instantiation.
+        fn is_entire_supply_locked(&self, badge_type: V1BadgeType) -> bool {
+            self.is_entire_supply(badge_type, self.vault(badge_type).amount())
+        }
+
+        // Whether `amount` is the entire supply snapshot of the badge resource. Always false for
+        // resources that did not track their total supply at instantiation.
+        fn is_entire_supply(&self, badge_type: V1BadgeType, amount: Decimal) -> bool {
+            self.supply_at_instantiation(badge_type)
+                .is_some_and(|supply| amount == supply)
+        }
+
+        fn is_complete(&self) -> bool {
+            self.is_complete_at(
+                self.admin_lock_stats.total_locked,
+                self.upgrade_lock_stats.total_locked,
+            )
+        }
+
+        // Whether the campaign is complete at the given cumulative locked amounts: the completion
+        // condition is met (or, without one, every configured lock target is), or the entire
+        // supply of both badge resources is locked
+        fn is_complete_at(&self, admin_total: Decimal, upgrade_total: Decimal) -> bool {
+            let thresholds_met = match &self.completion_condition {
+                Some(condition) => {
+                    admin_total >= condition.admin_badges_target
+                        && upgrade_total >= condition.upgrade_badges_target
+                }
+                None => self.targets_met_by(admin_total, upgrade_total),
+            };
+
+            thresholds_met
+                || (self.is_entire_supply(V1BadgeType::Admin, admin_total)
+                    && self.is_entire_supply(V1BadgeType::Upgrade, upgrade_total))
+        }
+
+        // Records completion of the campaign and emits the sealing event. With a completion
+        // condition, also sends the one-off "V1 Fully Relinquished" artifact to its archive
+        // account (via the receipt locker, should the account reject it). `completing_seq` is the
+        // sequence number of the completing lock, or None when completed by attestation.
+        fn complete(&mut self, completed_at: Instant, completing_seq: Option<u64>) {
+            self.completed_at = Some(completed_at);
+
+            let archive_account = self
+                .completion_condition
+                .as_ref()
+                .map(|condition| condition.archive_account);
+            if let Some(archive_account) = archive_account {
+                // Minted with a fixed supply of one, so no further artifact can ever exist
+                let artifact =
+                    ResourceBuilder::new_ruid_non_fungible::<V1FullyRelinquishedArtifact>(
+                        OwnerRole::None,
+                    )
+                    .metadata(metadata! {
+                        init {
+                            "name" => "V1 Fully Relinquished", locked;
+                            "description" => "Commemorates the completion of the RNS V1 badge relinquishment.", locked;
+                            "tags" => ["rns", "v1", "commemorative"], locked;
+                        }
+                    })
+                    .mint_initial_supply([V1FullyRelinquishedArtifact {
+                        completed_at,
+                        completing_seq,
+                        admin_badges_locked: self.admin_lock_stats.total_locked,
+                        upgrade_badges_locked: self.upgrade_lock_stats.total_locked,
+                        unique_contributors: self.unique_contributors,
+                    }]);
+                self.fully_relinquished_artifact = Some(artifact.resource_address());
+
+                self.receipt_locker.store(
+                    Global::<Account>::from(archive_account),
+                    artifact.into(),
+                    true,
+                );
+            }
+
+            Runtime::emit_event(RelinquishmentSealedEvent {
+                admin_badges_locked: self.admin_lock_stats.total_locked,
+                upgrade_badges_locked: self.upgrade_lock_stats.total_locked,
+                unique_contributors: self.unique_contributors,
+                lock_count: self.lock_count,
+                artifact_resource: self.fully_relinquished_artifact,
+                attested: completing_seq.is_none(),
+                sealed_at: completed_at,
+            });
+        }
+
+        // Splits a bucket according to `attributions` and locks each share for its beneficiary.
+        // Returns the RELINQUISHED tokens for the whole bucket.
+        fn lock_for_many(
+            &mut self,
+            badge_type: V1BadgeType,
+            mut badges: Bucket,
+            attributions: Vec<(ComponentAddress, Decimal)>,
+        ) -> Bucket {
+            assert!(
+                !attributions.is_empty(),
+                "At least one attribution is required"
+            );
+            assert!(
+                attributions.iter().all(|(_, amount)| amount.is_positive()),
+                "Attributed amounts must be positive"
+            );
+
+            let attributed_total = attributions
+                .iter()
+                .fold(Decimal::ZERO, |total, (_, amount)| total + *amount);
+            assert_eq!(
+                attributed_total,
+                badges.amount(),
+                "Attributed amounts sum to {} but the bucket holds {}",
+                attributed_total,
+                badges.amount()
+            );
+
+            for (beneficiary, amount) in attributions {
+                let share = badges.take(amount);
+                self.lock(badge_type, share, Some(beneficiary));
+            }
+
+            badges.drop_empty();
+
+            self.mint_relinquished(attributed_total)
+        }
+
+        // Adds an attributed lock to the contributor's statistics
+        fn record_contribution(
+            &mut self,
+            badge_type: V1BadgeType,
+            account: ComponentAddress,
+            amount: Decimal,
+            timestamp: Instant,
+        ) {
+            let mut stats = self.get_contributor_stats(account);
+            let previous_tier = self
+                .tier_thresholds
+                .tier_of(stats.admin_badges_locked + stats.upgrade_badges_locked);
+            if stats.lock_count == 0 {
+                self.contributor_index
+                    .insert(self.unique_contributors, account);
+                self.unique_contributors += 1;
+
+                Runtime::emit_event(NewContributorEvent {
+                    contributor: account,
+                    contributor_number: self.unique_contributors,
+                    timestamp,
+                });
+            }
+            match badge_type {
+                V1BadgeType::Admin => stats.admin_badges_locked += amount,
+                V1BadgeType::Upgrade => stats.upgrade_badges_locked += amount,
+            }
+            stats.lock_count += 1;
+            stats.first_locked_at.get_or_insert(timestamp);
+            stats.last_locked_at = Some(timestamp);
+
+            let total_locked = stats.admin_badges_locked + stats.upgrade_badges_locked;
+            let tier = self.tier_thresholds.tier_of(total_locked);
+            if tier != previous_tier {
+                self.upgrade_commemorative_tier(account, tier);
+            }
+
+            self.update_leaderboard(account, total_locked);
+            self.contributors.insert(account, stats);
+        }
+
+        // Updates the tier of the account's commemorative NFT, if it has been claimed
+        fn upgrade_commemorative_tier(
+            &self,
+            account: ComponentAddress,
+            tier: Option<V1CommemorativeTier>,
+        ) {
+            let manager = ResourceManager::from(self.commemorative_resource);
+            let nft_id = commemorative_nft_id(account);
+            if manager.non_fungible_exists(&nft_id) {
+                manager.update_non_fungible_data(&nft_id, "tier", tier);
+            }
+        }
+
+        // Moves the contributor to its sorted position for its new total
+        fn update_leaderboard(&mut self, account: ComponentAddress, total_locked: Decimal) {
+            self.leaderboard.retain(|(entry, _)| *entry != account);
+
+            let position = self
+                .leaderboard
+                .iter()
+                .position(|(_, entry_total)| *entry_total < total_locked)
+                .unwrap_or(self.leaderboard.len());
+
+            if position < MAX_LEADERBOARD_SIZE {
+                self.leaderboard.insert(position, (account, total_locked));
+                self.leaderboard.truncate(MAX_LEADERBOARD_SIZE);
+            }
+        }
+
+        // Resolves the account whose owner badge is presented in `account_proof`
+        fn contributor_from_proof(account_proof: Proof) -> ComponentAddress {
+            account_from_proof(account_proof, "Contributor")
+        }
+
+        fn badge_resource(&self, badge_type: V1BadgeType) -> ResourceAddress {
+            match badge_type {
+                V1BadgeType::Admin => self.v1_admin_badge_resource,
+                V1BadgeType::Upgrade => self.v1_upgrade_badge_resource,
+            }
+        }
+
+        // Badge type of the bucket's resource; panics if it is neither badge resource
+        fn badge_type_of_bucket(&self, badges: &Bucket) -> V1BadgeType {
+            self.badge_type_of(badges.resource_address())
+                .unwrap_or_else(|| {
+                    panic!(
+                        "Invalid V1 badge resource. Expected {:?} or {:?}, received {:?}",
+                        self.v1_admin_badge_resource,
+                        self.v1_upgrade_badge_resource,
+                        badges.resource_address()
+                    )
+                })
+        }
+
+        fn badge_type_of(&self, resource: ResourceAddress) -> Option<V1BadgeType> {
+            [V1BadgeType::Admin, V1BadgeType::Upgrade]
+                .into_iter()
+                .find(|badge_type| self.badge_resource(*badge_type) == resource)
+        }
+
+        fn vault(&self, badge_type: V1BadgeType) -> &Vault {
+            match badge_type {
+                V1BadgeType::Admin => &self.v1_admin_badges_vault,
+                V1BadgeType::Upgrade => &self.v1_upgrade_badges_vault,
+            }
+        }
+
+        // The badge type's vault as a non-fungible vault, if the badge resource is non-fungible
+        fn non_fungible_vault(&self, badge_type: V1BadgeType) -> Option<NonFungibleVault> {
+            let vault = self.vault(badge_type);
+            (!vault.resource_address().is_fungible()).then(|| vault.as_non_fungible())
+        }
+
+        fn vault_address(&self, badge_type: V1BadgeType) -> InternalAddress {
+            InternalAddress::new_or_panic(self.vault(badge_type).0.as_node_id().0)
+        }
+
+        fn vault_mut(&mut self, badge_type: V1BadgeType) -> &mut Vault {
+            match badge_type {
+                V1BadgeType::Admin => &mut self.v1_admin_badges_vault,
+                V1BadgeType::Upgrade => &mut self.v1_upgrade_badges_vault,
+            }
+        }
     }
 }
@@ -0,0 +1,177 @@
+use crate::rns_v1_badge_lockers::{assert_irreversibility_confirmed, V1BadgeType};
+use scrypto::prelude::*;
+
+// Amounts of V1 badges burned by a V1BadgeBurner
+#[derive(ScryptoSbor, Debug)]
+pub struct V1BurnStatus {
+    pub admin_badges_burned: Decimal,
+    pub upgrade_badges_burned: Decimal,
+    pub admin_badge_resource: ResourceAddress,
+    pub upgrade_badge_resource: ResourceAddress,
+}
+
+// Event emitted when V1 badges are burned
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct V1BadgesBurnedEvent {
+    pub resource_address: ResourceAddress,
+    pub badge_type: V1BadgeType,
+    pub amount_burned: Decimal,
+    // Running totals of each badge type burned, including this burn
+    pub admin_badges_burned: Decimal,
+    pub upgrade_badges_burned: Decimal,
+    pub timestamp: Instant,
+    pub epoch: Epoch,
+}
+
+#[blueprint]
+#[events(V1BadgesBurnedEvent)]
+mod v1_badge_burner {
+    use super::*;
+
+    enable_function_auth! {
+        instantiate => rule!(allow_all);
+    }
+
+    enable_method_auth! {
+        methods {
+            burn_admin_badges => PUBLIC;
+            burn_upgrade_badges => PUBLIC;
+            get_burn_status => PUBLIC;
+        }
+    }
+
+    // V1 Badge Burner
+    //
+    // A variant of V1AuthRelinquishment that burns deposited V1 badges instead of vaulting them,
+    // removing them from the supply altogether. Burning only succeeds if the badge resource lets
+    // anyone burn it, or if its burner role has been granted to this component. Like
+    // V1AuthRelinquishment, the burner has no owner and no roles.
+
+    pub struct V1BadgeBurner {
+        v1_admin_badge_resource: ResourceAddress,
+        v1_upgrade_badge_resource: ResourceAddress,
+
+        // Running totals of each badge type burned through this component
+        admin_badges_burned: Decimal,
+        upgrade_badges_burned: Decimal,
+    }
+
+    impl V1BadgeBurner {
+        // Instantiates a burner for the given V1 badge resources.
+        //
+        // # Arguments
+        // * `admin_resource` - Resource address of V1 admin badges
+        // * `upgrade_resource` - Resource address of V1 upgrade badges
+        //
+        // # Returns
+        // The globalized component
+        //
+        // # Panics
+        // * If both resource addresses are the same
+        pub fn instantiate(
+            admin_resource: ResourceAddress,
+            upgrade_resource: ResourceAddress,
+        ) -> Global<V1BadgeBurner> {
+            assert_ne!(
+                admin_resource, upgrade_resource,
+                "Admin and upgrade badge resources must be different"
+            );
+
+            Self {
+                v1_admin_badge_resource: admin_resource,
+                v1_upgrade_badge_resource: upgrade_resource,
+                admin_badges_burned: Decimal::ZERO,
+                upgrade_badges_burned: Decimal::ZERO,
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .metadata(metadata! {
+                init {
+                    "name" => "RNS V1 Badge Burner", locked;
+                    "description" => "Permanently burns RNS V1 admin and upgrade badges.", locked;
+                    "tags" => ["rns", "v1", "deprecation", "burn"], locked;
+                }
+            })
+            .globalize()
+        }
+
+        // Burns V1 admin badges.
+        //
+        // # Arguments
+        // * `badges` - Bucket containing V1 admin badges
+        // * `confirmation` - Must equal IRREVERSIBILITY_CONFIRMATION
+        //
+        // # Panics
+        // * If the confirmation does not equal IRREVERSIBILITY_CONFIRMATION
+        // * If the bucket contains a different resource or is empty
+        // * If the badge resource cannot be burned by this component
+        pub fn burn_admin_badges(&mut self, badges: Bucket, confirmation: String) {
+            self.burn(V1BadgeType::Admin, badges, confirmation);
+        }
+
+        // Burns V1 upgrade badges.
+        //
+        // # Arguments
+        // * `badges` - Bucket containing V1 upgrade badges
+        // * `confirmation` - Must equal IRREVERSIBILITY_CONFIRMATION
+        //
+        // # Panics
+        // * If the confirmation does not equal IRREVERSIBILITY_CONFIRMATION
+        // * If the bucket contains a different resource or is empty
+        // * If the badge resource cannot be burned by this component
+        pub fn burn_upgrade_badges(&mut self, badges: Bucket, confirmation: String) {
+            self.burn(V1BadgeType::Upgrade, badges, confirmation);
+        }
+
+        // Returns the amounts of V1 badges burned so far.
+        //
+        // # Returns
+        // `V1BurnStatus` with the running total of each badge type and the resource addresses
+        pub fn get_burn_status(&self) -> V1BurnStatus {
+            V1BurnStatus {
+                admin_badges_burned: self.admin_badges_burned,
+                upgrade_badges_burned: self.upgrade_badges_burned,
+                admin_badge_resource: self.v1_admin_badge_resource,
+                upgrade_badge_resource: self.v1_upgrade_badge_resource,
+            }
+        }
+
+        fn burn(&mut self, badge_type: V1BadgeType, badges: Bucket, confirmation: String) {
+            assert_irreversibility_confirmed(&confirmation);
+            let resource_address = match badge_type {
+                V1BadgeType::Admin => self.v1_admin_badge_resource,
+                V1BadgeType::Upgrade => self.v1_upgrade_badge_resource,
+            };
+            assert_eq!(
+                badges.resource_address(),
+                resource_address,
+                "Invalid V1 {} badge resource. Expected {:?}, received {:?}",
+                badge_type.label(),
+                resource_address,
+                badges.resource_address()
+            );
+            let amount_burned = badges.amount();
+            assert!(
+                amount_burned.is_positive(),
+                "Cannot burn an empty bucket of V1 {} badges",
+                badge_type.label()
+            );
+
+            badges.burn();
+            match badge_type {
+                V1BadgeType::Admin => self.admin_badges_burned += amount_burned,
+                V1BadgeType::Upgrade => self.upgrade_badges_burned += amount_burned,
+            }
+
+            Runtime::emit_event(V1BadgesBurnedEvent {
+                resource_address,
+                badge_type,
+                amount_burned,
+                admin_badges_burned: self.admin_badges_burned,
+                upgrade_badges_burned: self.upgrade_badges_burned,
+                timestamp: Clock::current_time_rounded_to_seconds(),
+                epoch: Runtime::current_epoch(),
+            });
+        }
+    }
+}
@@ -1,3 +1,10 @@
+use rns_v1_badge_lockers::escrow_relinquishment::*;
+use rns_v1_badge_lockers::locker_factory::*;
+use rns_v1_badge_lockers::locker_registry::*;
+use rns_v1_badge_lockers::multi_resource_locker::*;
+use rns_v1_badge_lockers::pledge_registry::*;
+use rns_v1_badge_lockers::rns_v1_badge_lockers::*;
+use rns_v1_badge_lockers::v1_badge_burner::*;
 use scrypto_test::prelude::*;
 
 struct Account {
@@ -8,12 +15,73 @@ struct Account {
 struct TestEnv {
     ledger: DefaultLedgerSimulator,
     account: Account,
+    package_address: PackageAddress,
     v1_admin_badge_resource: ResourceAddress,
     v1_upgrade_badge_resource: ResourceAddress,
     component_address: ComponentAddress,
 }
 
+fn default_config(dapp_definition: ComponentAddress) -> V1LockerConfig {
+    V1LockerConfig {
+        dapp_definition: dapp_definition.into(),
+        claimed_websites: vec![UncheckedOrigin("https://radixnameservice.io".to_string())],
+        claimed_entities: vec![],
+        icon_url: UncheckedUrl("https://radixnameservice.io/icon.png".to_string()),
+        info_url: UncheckedUrl("https://radixnameservice.io".to_string()),
+        whole_units_only: false,
+        team_accounts: vec![],
+        tier_thresholds: V1TierThresholds {
+            bronze: dec!("1"),
+            silver: dec!("5"),
+            gold: dec!("10"),
+        },
+        receipt_branding: V1ResourceBranding {
+            name: "V1 Relinquishment Receipt".to_string(),
+            description: "Proof of locking RNS V1 badges".to_string(),
+            icon_url: UncheckedUrl("https://radixnameservice.io/receipt.png".to_string()),
+            info_url: UncheckedUrl("https://radixnameservice.io".to_string()),
+        },
+        soulbound_receipts: true,
+        completion_condition: None,
+        admin_milestones: vec![],
+        upgrade_milestones: vec![],
+        admin_target: None,
+        upgrade_target: None,
+        campaign_start_epoch: None,
+        campaign_end_epoch: None,
+        staging_window_minutes: 60,
+        other_v1_resources: vec![],
+        admin_checkpoint_interval: None,
+        upgrade_checkpoint_interval: None,
+        late_lock_policy: V1LateLockPolicy::Refuse,
+        attestation: None,
+        min_notice_proof_amount: dec!("1"),
+        commemorative_branding: V1ResourceBranding {
+            name: "V1 Relinquishment Commemorative".to_string(),
+            description: "Commemorates the RNS V1 relinquishment".to_string(),
+            icon_url: UncheckedUrl("https://radixnameservice.io/commemorative.png".to_string()),
+            info_url: UncheckedUrl("https://radixnameservice.io".to_string()),
+        },
+    }
+}
+
 fn setup() -> TestEnv {
+    setup_with_config(|config| config)
+}
+
+fn setup_with_config(configure: impl FnOnce(V1LockerConfig) -> V1LockerConfig) -> TestEnv {
+    setup_with(
+        FungibleResourceRoles::default(),
+        FungibleResourceRoles::default(),
+        configure,
+    )
+}
+
+fn setup_with(
+    admin_badge_roles: FungibleResourceRoles,
+    upgrade_badge_roles: FungibleResourceRoles,
+    configure: impl FnOnce(V1LockerConfig) -> V1LockerConfig,
+) -> TestEnv {
     let mut ledger = LedgerSimulatorBuilder::new().build();
 
     let (public_key, _, account_address) = ledger.new_allocated_account();
@@ -27,9 +95,9 @@ fn setup() -> TestEnv {
         .lock_fee_from_faucet()
         .create_fungible_resource(
             OwnerRole::None,
-            false,
+            true,
             18,
-            FungibleResourceRoles::default(),
+            admin_badge_roles,
             metadata!(
                 init {
                     "name" => "V1 Admin Badge (Test)", locked;
@@ -52,9 +120,9 @@ fn setup() -> TestEnv {
         .lock_fee_from_faucet()
         .create_fungible_resource(
             OwnerRole::None,
-            false,
+            true,
             18,
-            FungibleResourceRoles::default(),
+            upgrade_badge_roles,
             metadata!(
                 init {
                     "name" => "V1 Upgrade Badge (Test)", locked;
@@ -72,20 +140,101 @@ fn setup() -> TestEnv {
     );
     let v1_upgrade_badge_resource = upgrade_receipt.expect_commit(true).new_resource_addresses()[0];
 
-    // Instantiate the V1AuthRelinquishment component
+    instantiate_locker(
+        ledger,
+        account,
+        v1_admin_badge_resource,
+        v1_upgrade_badge_resource,
+        configure,
+    )
+}
+
+// Non-fungible data of the test V1 admin badges
+#[derive(ScryptoSbor, ManifestSbor, NonFungibleData)]
+struct TestBadgeData {
+    name: String,
+}
+
+// Sets up a locker whose admin badges are non-fungible, with integer ids 1 to 3 held by the
+// test account
+fn setup_with_non_fungible_admin_badges() -> TestEnv {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+
+    let (public_key, _, account_address) = ledger.new_allocated_account();
+    let account = Account {
+        public_key,
+        account_address,
+    };
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_non_fungible_resource(
+            OwnerRole::None,
+            NonFungibleIdType::Integer,
+            true,
+            NonFungibleResourceRoles::default(),
+            metadata!(),
+            Some((1..=3u64).map(|id| {
+                (
+                    NonFungibleLocalId::integer(id),
+                    TestBadgeData {
+                        name: format!("V1 Admin Badge #{}", id),
+                    },
+                )
+            })),
+        )
+        .create_fungible_resource(
+            OwnerRole::None,
+            true,
+            18,
+            FungibleResourceRoles::default(),
+            metadata!(),
+            Some(dec!("1000")),
+        )
+        .deposit_batch(account_address, ManifestExpression::EntireWorktop)
+        .build();
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    let new_resources = receipt.expect_commit(true).new_resource_addresses().clone();
+
+    instantiate_locker(
+        ledger,
+        account,
+        new_resources[0],
+        new_resources[1],
+        |config| config,
+    )
+}
+
+// Publishes the package and instantiates the V1AuthRelinquishment component for the given
+// badge resources
+fn instantiate_locker(
+    mut ledger: DefaultLedgerSimulator,
+    account: Account,
+    v1_admin_badge_resource: ResourceAddress,
+    v1_upgrade_badge_resource: ResourceAddress,
+    configure: impl FnOnce(V1LockerConfig) -> V1LockerConfig,
+) -> TestEnv {
+    let package_address = ledger.compile_and_publish(this_package!());
     let manifest = ManifestBuilder::new()
         .lock_fee_from_faucet()
         .call_function(
-            ledger.compile_and_publish(this_package!()),
+            package_address,
             "V1AuthRelinquishment",
             "instantiate",
-            manifest_args!(v1_admin_badge_resource, v1_upgrade_badge_resource),
+            manifest_args!(
+                v1_admin_badge_resource,
+                v1_upgrade_badge_resource,
+                configure(default_config(account.account_address))
+            ),
         )
         .build();
 
     let receipt = ledger.execute_manifest(
         manifest,
-        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        vec![NonFungibleGlobalId::from_public_key(&account.public_key)],
     );
 
     let component_address = receipt.expect_commit(true).new_component_addresses()[0];
@@ -93,12 +242,225 @@ fn setup() -> TestEnv {
     TestEnv {
         ledger,
         account,
+        package_address,
         v1_admin_badge_resource,
         v1_upgrade_badge_resource,
         component_address,
     }
 }
 
+// Withdraws `amount` of `resource` from the test account and passes it to a lock method
+fn lock_badges(
+    env: &mut TestEnv,
+    method: &str,
+    resource: ResourceAddress,
+    amount: Decimal,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(env.account.account_address, resource, amount)
+        .take_all_from_worktop(resource, "badges")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                method,
+                (
+                    lookup.bucket("badges"),
+                    None::<ManifestProof>,
+                    IRREVERSIBILITY_CONFIRMATION,
+                ),
+            )
+        })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+
+    env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    )
+}
+
+// Creates a new account whose owner badge is held by the test account, so the test account
+// can attribute locks to it. Returns the new account and its owner badge local id.
+fn create_contributor_account(env: &mut TestEnv) -> (ComponentAddress, NonFungibleLocalId) {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .new_account()
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+    let contributor = receipt.expect_commit_success().new_component_addresses()[0];
+    let owner_badge = NonFungibleLocalId::bytes(contributor.as_node_id().0).unwrap();
+
+    (contributor, owner_badge)
+}
+
+// Like `lock_badges`, but attributes the lock with a proof of the given account owner badge
+fn lock_badges_as(
+    env: &mut TestEnv,
+    method: &str,
+    resource: ResourceAddress,
+    amount: Decimal,
+    owner_badge: &NonFungibleLocalId,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(env.account.account_address, resource, amount)
+        .take_all_from_worktop(resource, "badges")
+        .create_proof_from_account_of_non_fungibles(
+            env.account.account_address,
+            ACCOUNT_OWNER_BADGE,
+            [owner_badge.clone()],
+        )
+        .pop_from_auth_zone("account_proof")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                method,
+                (
+                    lookup.bucket("badges"),
+                    Some(lookup.proof("account_proof")),
+                    IRREVERSIBILITY_CONFIRMATION,
+                ),
+            )
+        })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+
+    env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    )
+}
+
+// Locks badges from the test account on behalf of `beneficiary`
+fn lock_badges_for(
+    env: &mut TestEnv,
+    method: &str,
+    resource: ResourceAddress,
+    amount: Decimal,
+    beneficiary: ComponentAddress,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(env.account.account_address, resource, amount)
+        .take_all_from_worktop(resource, "badges")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                method,
+                (
+                    beneficiary,
+                    lookup.bucket("badges"),
+                    IRREVERSIBILITY_CONFIRMATION,
+                ),
+            )
+        })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+
+    env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    )
+}
+
+fn get_contributor_totals(env: &mut TestEnv, account: ComponentAddress) -> (Decimal, Decimal) {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "get_contributor_totals",
+            manifest_args!(account),
+        )
+        .build();
+
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success()
+        .output(1)
+}
+
+fn get_lock_status_v2(env: &mut TestEnv) -> V1LockStatusV2 {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "get_lock_status_v2",
+            manifest_args!(),
+        )
+        .build();
+
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success()
+        .output(1)
+}
+
+fn get_lock_status(env: &mut TestEnv) -> V1LockStatus {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(env.component_address, "get_lock_status", manifest_args!())
+        .build();
+
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success()
+        .output(1)
+}
+
+// Address of the component's internal vault holding `resource`
+fn locker_vault(env: &mut TestEnv, resource: ResourceAddress) -> InternalAddress {
+    let vaults = env
+        .ledger
+        .get_component_vaults(env.component_address, resource);
+    assert_eq!(
+        vaults.len(),
+        1,
+        "Expected exactly one vault per badge resource"
+    );
+    InternalAddress::new_or_panic(vaults[0].0)
+}
+
 #[test]
 fn test_instantiation() {
     let mut env = setup();
@@ -119,6 +481,70 @@ fn test_instantiation() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn test_instantiation_sets_wallet_metadata() {
+    let mut env = setup();
+
+    assert_eq!(
+        env.ledger
+            .get_metadata(env.component_address.into(), "dapp_definition"),
+        Some(MetadataValue::GlobalAddress(
+            env.account.account_address.into()
+        ))
+    );
+    assert_eq!(
+        env.ledger
+            .get_metadata(env.component_address.into(), "claimed_websites"),
+        Some(MetadataValue::OriginArray(vec![UncheckedOrigin(
+            "https://radixnameservice.io".to_string()
+        )]))
+    );
+}
+
+#[test]
+fn test_method_auth_template_is_all_public() {
+    let mut env = setup();
+
+    let definitions = env
+        .ledger
+        .get_package_blueprint_definitions(&env.package_address);
+    let definition = definitions
+        .get(&BlueprintVersionKey::new_default("V1AuthRelinquishment"))
+        .expect("V1AuthRelinquishment blueprint should be published");
+
+    let MethodAuthTemplate::StaticRoleDefinition(static_roles) =
+        &definition.auth_config.method_auth
+    else {
+        panic!("Method auth should be declared explicitly");
+    };
+
+    // No roles are defined, so nothing can be privileged
+    match &static_roles.roles {
+        RoleSpecification::Normal(roles) => assert!(roles.is_empty(), "No roles expected"),
+        RoleSpecification::UseOuter => panic!("Unexpected outer role specification"),
+    }
+
+    for method in [
+        "lock_admin_badges",
+        "lock_upgrade_badges",
+        "get_lock_status",
+    ] {
+        assert!(
+            static_roles.methods.contains_key(&MethodKey::new(method)),
+            "{} should be declared",
+            method
+        );
+    }
+
+    for (method, accessibility) in static_roles.methods.iter() {
+        assert!(
+            matches!(accessibility, MethodAccessibility::Public),
+            "{:?} should be public",
+            method
+        );
+    }
+}
+
 #[test]
 fn test_lock_admin_badges() {
     let mut env = setup();
@@ -136,9 +562,17 @@ fn test_lock_admin_badges() {
             builder.call_method(
                 env.component_address,
                 "lock_admin_badges",
-                (lookup.bucket("admin_badges"),),
+                (
+                    lookup.bucket("admin_badges"),
+                    None::<ManifestProof>,
+                    IRREVERSIBILITY_CONFIRMATION,
+                ),
             )
         })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
         .build();
 
     let receipt = env.ledger.execute_manifest(
@@ -177,9 +611,17 @@ fn test_lock_upgrade_badges() {
             builder.call_method(
                 env.component_address,
                 "lock_upgrade_badges",
-                (lookup.bucket("upgrade_badges"),),
+                (
+                    lookup.bucket("upgrade_badges"),
+                    None::<ManifestProof>,
+                    IRREVERSIBILITY_CONFIRMATION,
+                ),
             )
         })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
         .build();
 
     let receipt = env.ledger.execute_manifest(
@@ -219,9 +661,17 @@ fn test_cumulative_locking() {
                 builder.call_method(
                     env.component_address,
                     "lock_admin_badges",
-                    (lookup.bucket("admin_badges"),),
+                    (
+                        lookup.bucket("admin_badges"),
+                        None::<ManifestProof>,
+                        IRREVERSIBILITY_CONFIRMATION,
+                    ),
                 )
             })
+            .deposit_batch(
+                env.account.account_address,
+                ManifestExpression::EntireWorktop,
+            )
             .build();
 
         env.ledger
@@ -248,9 +698,17 @@ fn test_cumulative_locking() {
                 builder.call_method(
                     env.component_address,
                     "lock_upgrade_badges",
-                    (lookup.bucket("upgrade_badges"),),
+                    (
+                        lookup.bucket("upgrade_badges"),
+                        None::<ManifestProof>,
+                        IRREVERSIBILITY_CONFIRMATION,
+                    ),
                 )
             })
+            .deposit_batch(
+                env.account.account_address,
+                ManifestExpression::EntireWorktop,
+            )
             .build();
 
         env.ledger
@@ -288,7 +746,7 @@ fn test_invalid_admin_badge_resource() {
         .lock_fee_from_faucet()
         .create_fungible_resource(
             OwnerRole::None,
-            false,
+            true,
             18,
             FungibleResourceRoles::default(),
             metadata!(
@@ -321,9 +779,17 @@ fn test_invalid_admin_badge_resource() {
             builder.call_method(
                 env.component_address,
                 "lock_admin_badges",
-                (lookup.bucket("fake_badges"),),
+                (
+                    lookup.bucket("fake_badges"),
+                    None::<ManifestProof>,
+                    IRREVERSIBILITY_CONFIRMATION,
+                ),
             )
         })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
         .build();
 
     let receipt = env.ledger.execute_manifest(
@@ -345,7 +811,7 @@ fn test_invalid_upgrade_badge_resource() {
         .lock_fee_from_faucet()
         .create_fungible_resource(
             OwnerRole::None,
-            false,
+            true,
             18,
             FungibleResourceRoles::default(),
             metadata!(
@@ -378,7 +844,92 @@ fn test_invalid_upgrade_badge_resource() {
             builder.call_method(
                 env.component_address,
                 "lock_upgrade_badges",
-                (lookup.bucket("fake_badges"),),
+                (
+                    lookup.bucket("fake_badges"),
+                    None::<ManifestProof>,
+                    IRREVERSIBILITY_CONFIRMATION,
+                ),
+            )
+        })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_post_notice_with_admin_badge_proof() {
+    let mut env = setup();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(
+            env.account.account_address,
+            env.v1_admin_badge_resource,
+            dec!("1"),
+        )
+        .pop_from_auth_zone("admin_proof")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                "post_notice",
+                (
+                    lookup.proof("admin_proof"),
+                    "V1 deprecation schedule".to_string(),
+                    hash("Full notice body"),
+                ),
+            )
+        })
+        .build();
+
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+
+    assert!(
+        !receipt
+            .expect_commit_success()
+            .application_events
+            .is_empty(),
+        "Should emit NoticePostedEvent"
+    );
+}
+
+#[test]
+fn test_post_notice_rejects_non_admin_proof() {
+    let mut env = setup();
+
+    // An upgrade badge proof is not sufficient to post notices
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(
+            env.account.account_address,
+            env.v1_upgrade_badge_resource,
+            dec!("1"),
+        )
+        .pop_from_auth_zone("upgrade_proof")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                "post_notice",
+                (
+                    lookup.proof("upgrade_proof"),
+                    "Unauthorized notice".to_string(),
+                    hash("Full notice body"),
+                ),
             )
         })
         .build();
@@ -392,3 +943,4164 @@ fn test_invalid_upgrade_badge_resource() {
 
     receipt.expect_commit_failure();
 }
+
+#[test]
+fn test_post_notice_rejects_dust_admin_proof() {
+    let mut env = setup();
+
+    // A fraction of an admin badge is below the configured minimum of one
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(
+            env.account.account_address,
+            env.v1_admin_badge_resource,
+            dec!("0.000000000000000001"),
+        )
+        .pop_from_auth_zone("admin_proof")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                "post_notice",
+                (
+                    lookup.proof("admin_proof"),
+                    "Dust notice".to_string(),
+                    hash("Full notice body"),
+                ),
+            )
+        })
+        .build();
+
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_get_notices_pagination() {
+    let mut env = setup();
+
+    for title in ["First notice", "Second notice", "Third notice"] {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(
+                env.account.account_address,
+                env.v1_admin_badge_resource,
+                dec!("1"),
+            )
+            .pop_from_auth_zone("admin_proof")
+            .with_name_lookup(|builder, lookup| {
+                builder.call_method(
+                    env.component_address,
+                    "post_notice",
+                    (lookup.proof("admin_proof"), title.to_string(), hash(title)),
+                )
+            })
+            .build();
+
+        env.ledger
+            .execute_manifest(
+                manifest,
+                vec![NonFungibleGlobalId::from_public_key(
+                    &env.account.public_key,
+                )],
+            )
+            .expect_commit_success();
+    }
+
+    // Page through notices two at a time
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "get_notices",
+            manifest_args!(1u64, 2u32),
+        )
+        .build();
+
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+
+    let notices: Vec<Notice> = receipt.expect_commit_success().output(1);
+    assert_eq!(notices.len(), 2);
+    assert_eq!(notices[0].notice_id, 1);
+    assert_eq!(notices[0].title, "Second notice");
+    assert_eq!(notices[1].title, "Third notice");
+    assert!(notices[0].poster_badge_ids.is_empty());
+}
+
+#[test]
+fn test_whole_units_only_rejects_fractional_amounts() {
+    let mut env = setup_with_config(|config| V1LockerConfig {
+        whole_units_only: true,
+        ..config
+    });
+
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_badges(
+        &mut env,
+        "lock_upgrade_badges",
+        upgrade_resource,
+        dec!("0.5"),
+    )
+    .expect_commit_failure();
+    lock_badges(&mut env, "lock_upgrade_badges", upgrade_resource, dec!("2"))
+        .expect_commit_success();
+}
+
+// The locker cannot protect against the badge resource's own roles: if the resource was
+// created with a recaller role, badges can be recalled straight out of the locker's vault. It
+// can only flag the risk in its status.
+#[test]
+fn test_recallable_badges_can_be_recalled_from_locker() {
+    let recallable = FungibleResourceRoles {
+        recall_roles: recall_roles! {
+            recaller => rule!(allow_all);
+            recaller_updater => rule!(deny_all);
+        },
+        ..Default::default()
+    };
+    let mut env = setup_with(recallable, FungibleResourceRoles::default(), |config| {
+        config
+    });
+
+    // The instantiate-time risk check flags the live recaller
+    let status = get_lock_status_v2(&mut env);
+    assert!(status.admin_badge_risks.recallable);
+    assert!(!status.admin_badge_risks.freezable);
+    assert_eq!(status.upgrade_badge_risks, V1ResourceRisks::default());
+
+    let admin_resource = env.v1_admin_badge_resource;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("5")).expect_commit_success();
+
+    let vault = locker_vault(&mut env, admin_resource);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .recall(vault, dec!("5"))
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success();
+
+    assert_eq!(get_lock_status(&mut env).admin_badges_locked, dec!("0"));
+}
+
+#[test]
+fn test_non_recallable_badges_cannot_be_recalled_from_locker() {
+    let mut env = setup();
+    let status = get_lock_status_v2(&mut env);
+    assert_eq!(status.admin_badge_risks, V1ResourceRisks::default());
+    assert_eq!(status.upgrade_badge_risks, V1ResourceRisks::default());
+
+    let admin_resource = env.v1_admin_badge_resource;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("5")).expect_commit_success();
+
+    let vault = locker_vault(&mut env, admin_resource);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .recall(vault, dec!("5"))
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_failure();
+
+    assert_eq!(get_lock_status(&mut env).admin_badges_locked, dec!("5"));
+}
+
+// Freezing cannot release locked badges (there is no withdrawal path to freeze), but a
+// deposit freeze on the locker's vault blocks any further locking of that resource.
+#[test]
+fn test_deposit_frozen_vault_blocks_further_locks() {
+    let freezable = FungibleResourceRoles {
+        freeze_roles: freeze_roles! {
+            freezer => rule!(allow_all);
+            freezer_updater => rule!(deny_all);
+        },
+        ..Default::default()
+    };
+    let mut env = setup_with(FungibleResourceRoles::default(), freezable, |config| config);
+    let status = get_lock_status_v2(&mut env);
+    assert!(status.upgrade_badge_risks.freezable);
+    assert!(!status.upgrade_badge_risks.recallable);
+
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_badges(&mut env, "lock_upgrade_badges", upgrade_resource, dec!("1"))
+        .expect_commit_success();
+
+    let vault = locker_vault(&mut env, upgrade_resource);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .freeze_deposit(vault)
+        .build();
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success();
+
+    lock_badges(&mut env, "lock_upgrade_badges", upgrade_resource, dec!("1"))
+        .expect_commit_failure();
+    assert_eq!(get_lock_status(&mut env).upgrade_badges_locked, dec!("1"));
+}
+
+#[test]
+fn test_attributed_locks_are_recorded_per_contributor() {
+    let mut env = setup();
+    let (contributor, owner_badge) = create_contributor_account(&mut env);
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_badges_as(
+        &mut env,
+        "lock_admin_badges",
+        admin_resource,
+        dec!("4"),
+        &owner_badge,
+    )
+    .expect_commit_success();
+    lock_badges_as(
+        &mut env,
+        "lock_admin_badges",
+        admin_resource,
+        dec!("1"),
+        &owner_badge,
+    )
+    .expect_commit_success();
+    lock_badges_as(
+        &mut env,
+        "lock_upgrade_badges",
+        upgrade_resource,
+        dec!("2"),
+        &owner_badge,
+    )
+    .expect_commit_success();
+
+    // Unattributed locks count towards the totals but not towards any contributor
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("3")).expect_commit_success();
+
+    assert_eq!(
+        get_contributor_totals(&mut env, contributor),
+        (dec!("5"), dec!("2"))
+    );
+    let unattributed_account = env.account.account_address;
+    assert_eq!(
+        get_contributor_totals(&mut env, unattributed_account),
+        (dec!("0"), dec!("0"))
+    );
+    assert_eq!(get_lock_status(&mut env).admin_badges_locked, dec!("8"));
+}
+
+#[test]
+fn test_attribution_rejects_non_owner_badge_proof() {
+    let mut env = setup();
+
+    // A proof of some other resource cannot be used to claim a contribution
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(
+            env.account.account_address,
+            env.v1_admin_badge_resource,
+            dec!("1"),
+        )
+        .take_all_from_worktop(env.v1_admin_badge_resource, "admin_badges")
+        .create_proof_from_account_of_amount(
+            env.account.account_address,
+            env.v1_upgrade_badge_resource,
+            dec!("1"),
+        )
+        .pop_from_auth_zone("fake_proof")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                "lock_admin_badges",
+                (
+                    lookup.bucket("admin_badges"),
+                    Some(lookup.proof("fake_proof")),
+                    IRREVERSIBILITY_CONFIRMATION,
+                ),
+            )
+        })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_get_contributor_stats() {
+    let mut env = setup();
+    let (contributor, owner_badge) = create_contributor_account(&mut env);
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_badges_as(
+        &mut env,
+        "lock_admin_badges",
+        admin_resource,
+        dec!("2"),
+        &owner_badge,
+    )
+    .expect_commit_success();
+    lock_badges_as(
+        &mut env,
+        "lock_upgrade_badges",
+        upgrade_resource,
+        dec!("1"),
+        &owner_badge,
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "get_contributor_stats",
+            manifest_args!(contributor),
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+
+    let stats: ContributorStats = receipt.expect_commit_success().output(1);
+    assert_eq!(stats.admin_badges_locked, dec!("2"));
+    assert_eq!(stats.upgrade_badges_locked, dec!("1"));
+    assert_eq!(stats.lock_count, 2);
+    assert!(stats.first_locked_at.is_some());
+    assert!(stats.first_locked_at <= stats.last_locked_at);
+}
+
+#[test]
+fn test_unique_contributor_count() {
+    let mut env = setup();
+    let (_, first_badge) = create_contributor_account(&mut env);
+    let (_, second_badge) = create_contributor_account(&mut env);
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_badges_as(
+        &mut env,
+        "lock_admin_badges",
+        admin_resource,
+        dec!("1"),
+        &first_badge,
+    )
+    .expect_commit_success();
+    lock_badges_as(
+        &mut env,
+        "lock_upgrade_badges",
+        upgrade_resource,
+        dec!("1"),
+        &first_badge,
+    )
+    .expect_commit_success();
+    lock_badges_as(
+        &mut env,
+        "lock_admin_badges",
+        admin_resource,
+        dec!("1"),
+        &second_badge,
+    )
+    .expect_commit_success();
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("1")).expect_commit_success();
+
+    let status = get_lock_status_v2(&mut env);
+    assert_eq!(status.unique_contributors, 2);
+    assert_eq!(status.admin_badges_locked, dec!("3"));
+}
+
+#[test]
+fn test_top_contributors_leaderboard() {
+    let mut env = setup();
+    let (small, small_badge) = create_contributor_account(&mut env);
+    let (large, large_badge) = create_contributor_account(&mut env);
+    let (medium, medium_badge) = create_contributor_account(&mut env);
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_badges_as(
+        &mut env,
+        "lock_admin_badges",
+        admin_resource,
+        dec!("1"),
+        &small_badge,
+    )
+    .expect_commit_success();
+    lock_badges_as(
+        &mut env,
+        "lock_admin_badges",
+        admin_resource,
+        dec!("3"),
+        &medium_badge,
+    )
+    .expect_commit_success();
+    lock_badges_as(
+        &mut env,
+        "lock_admin_badges",
+        admin_resource,
+        dec!("2"),
+        &large_badge,
+    )
+    .expect_commit_success();
+    // Totals combine both badge types, moving this contributor to the top
+    lock_badges_as(
+        &mut env,
+        "lock_upgrade_badges",
+        upgrade_resource,
+        dec!("5"),
+        &large_badge,
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "top_contributors",
+            manifest_args!(2u32),
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+
+    let top: Vec<(ComponentAddress, Decimal)> = receipt.expect_commit_success().output(1);
+    assert_eq!(top, vec![(large, dec!("7")), (medium, dec!("3"))]);
+    assert!(!top.iter().any(|(account, _)| *account == small));
+}
+
+#[test]
+fn test_lock_on_behalf_of_beneficiary() {
+    let mut env = setup();
+    let (beneficiary, _) = create_contributor_account(&mut env);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(
+            env.account.account_address,
+            env.v1_upgrade_badge_resource,
+            dec!("3"),
+        )
+        .take_all_from_worktop(env.v1_upgrade_badge_resource, "upgrade_badges")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                "lock_upgrade_badges_for",
+                (
+                    beneficiary,
+                    lookup.bucket("upgrade_badges"),
+                    IRREVERSIBILITY_CONFIRMATION,
+                ),
+            )
+        })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success();
+
+    // The beneficiary is credited, not the account that supplied the badges
+    assert_eq!(
+        get_contributor_totals(&mut env, beneficiary),
+        (dec!("0"), dec!("3"))
+    );
+    let custodian = env.account.account_address;
+    assert_eq!(
+        get_contributor_totals(&mut env, custodian),
+        (dec!("0"), dec!("0"))
+    );
+}
+
+fn lock_admin_badges_for_many(
+    env: &mut TestEnv,
+    amount: Decimal,
+    attributions: Vec<(ComponentAddress, Decimal)>,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(
+            env.account.account_address,
+            env.v1_admin_badge_resource,
+            amount,
+        )
+        .take_all_from_worktop(env.v1_admin_badge_resource, "admin_badges")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                "lock_admin_badges_for_many",
+                (
+                    lookup.bucket("admin_badges"),
+                    attributions.clone(),
+                    IRREVERSIBILITY_CONFIRMATION,
+                ),
+            )
+        })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+
+    env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    )
+}
+
+#[test]
+fn test_batch_attribution_credits_each_beneficiary() {
+    let mut env = setup();
+    let (first, _) = create_contributor_account(&mut env);
+    let (second, _) = create_contributor_account(&mut env);
+
+    lock_admin_badges_for_many(
+        &mut env,
+        dec!("10"),
+        vec![(first, dec!("6")), (second, dec!("4"))],
+    )
+    .expect_commit_success();
+
+    assert_eq!(
+        get_contributor_totals(&mut env, first),
+        (dec!("6"), dec!("0"))
+    );
+    assert_eq!(
+        get_contributor_totals(&mut env, second),
+        (dec!("4"), dec!("0"))
+    );
+    assert_eq!(get_lock_status(&mut env).admin_badges_locked, dec!("10"));
+}
+
+#[test]
+fn test_batch_attribution_must_match_bucket_amount() {
+    let mut env = setup();
+    let (first, _) = create_contributor_account(&mut env);
+
+    lock_admin_badges_for_many(&mut env, dec!("10"), vec![(first, dec!("9"))])
+        .expect_commit_failure();
+}
+
+#[test]
+fn test_team_and_community_locks_are_split() {
+    let mut env = setup();
+    let (team_account, team_badge) = create_contributor_account(&mut env);
+    let (community_account, _) = create_contributor_account(&mut env);
+
+    // The team account only exists once the ledger does, so the locker is instantiated here
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            env.package_address,
+            "V1AuthRelinquishment",
+            "instantiate",
+            manifest_args!(
+                admin_resource,
+                upgrade_resource,
+                V1LockerConfig {
+                    team_accounts: vec![team_account],
+                    ..default_config(env.account.account_address)
+                }
+            ),
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+    env.component_address = receipt.expect_commit(true).new_component_addresses()[0];
+
+    lock_badges_as(
+        &mut env,
+        "lock_admin_badges",
+        admin_resource,
+        dec!("4"),
+        &team_badge,
+    )
+    .expect_commit_success();
+    lock_badges_for(
+        &mut env,
+        "lock_admin_badges_for",
+        admin_resource,
+        dec!("2"),
+        community_account,
+    )
+    .expect_commit_success();
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("1")).expect_commit_success();
+
+    // Locks made for the team account without its proof count as the community's
+    lock_badges_for(
+        &mut env,
+        "lock_admin_badges_for",
+        admin_resource,
+        dec!("5"),
+        team_account,
+    )
+    .expect_commit_success();
+
+    let status = get_lock_status_v2(&mut env);
+    assert_eq!(status.team_admin_badges_locked, dec!("4"));
+    assert_eq!(status.community_admin_badges_locked, dec!("8"));
+    assert_eq!(status.team_upgrade_badges_locked, dec!("0"));
+}
+
+fn get_lock_record(env: &mut TestEnv, seq: u64) -> Option<LockRecord> {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "get_lock_record",
+            manifest_args!(seq),
+        )
+        .build();
+
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success()
+        .output(1)
+}
+
+#[test]
+fn test_lock_records_are_sequenced() {
+    let mut env = setup();
+    let (contributor, owner_badge) = create_contributor_account(&mut env);
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("2")).expect_commit_success();
+    lock_badges_as(
+        &mut env,
+        "lock_upgrade_badges",
+        upgrade_resource,
+        dec!("1"),
+        &owner_badge,
+    )
+    .expect_commit_success();
+
+    let first = get_lock_record(&mut env, 0).expect("First lock should be recorded");
+    assert_eq!(first.seq, 0);
+    assert_eq!(first.badge_type, V1BadgeType::Admin);
+    assert_eq!(first.amount, dec!("2"));
+    assert_eq!(first.contributor, None);
+
+    let second = get_lock_record(&mut env, 1).expect("Second lock should be recorded");
+    assert_eq!(second.seq, 1);
+    assert_eq!(second.badge_type, V1BadgeType::Upgrade);
+    assert_eq!(second.contributor, Some(contributor));
+
+    assert!(get_lock_record(&mut env, 2).is_none());
+}
+
+#[test]
+fn test_lock_history_pagination() {
+    let mut env = setup();
+
+    let admin_resource = env.v1_admin_badge_resource;
+    for amount in [dec!("1"), dec!("2"), dec!("3"), dec!("4")] {
+        lock_badges(&mut env, "lock_admin_badges", admin_resource, amount).expect_commit_success();
+    }
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "get_lock_history",
+            manifest_args!(2u64, 10u32),
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+
+    let history: Vec<LockRecord> = receipt.expect_commit_success().output(1);
+    let amounts: Vec<Decimal> = history.iter().map(|record| record.amount).collect();
+    assert_eq!(amounts, vec![dec!("3"), dec!("4")]);
+    assert_eq!(history[0].seq, 2);
+}
+
+#[test]
+fn test_epoch_stats_aggregate_locks() {
+    let mut env = setup();
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("2")).expect_commit_success();
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("3")).expect_commit_success();
+    lock_badges(&mut env, "lock_upgrade_badges", upgrade_resource, dec!("1"))
+        .expect_commit_success();
+
+    let epoch = env.ledger.get_current_epoch();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "get_epoch_stats",
+            manifest_args!(epoch),
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+
+    let stats: EpochStats = receipt.expect_commit_success().output(1);
+    assert_eq!(stats.admin_badges_locked, dec!("5"));
+    assert_eq!(stats.upgrade_badges_locked, dec!("1"));
+    assert_eq!(stats.lock_count, 3);
+}
+
+#[test]
+fn test_lock_timeline_in_extended_status() {
+    let mut env = setup();
+
+    let status = get_lock_status_v2(&mut env);
+    assert!(status.admin_lock_stats.first_locked_at.is_none());
+
+    let admin_resource = env.v1_admin_badge_resource;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("1")).expect_commit_success();
+    env.ledger
+        .advance_to_round_at_timestamp(Round::of(2), 3_600_000);
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("1")).expect_commit_success();
+
+    let status = get_lock_status_v2(&mut env);
+    let first = status.admin_lock_stats.first_locked_at.unwrap();
+    let last = status.admin_lock_stats.last_locked_at.unwrap();
+    assert!(first < last, "Last lock should be later than the first");
+    assert!(status.upgrade_lock_stats.first_locked_at.is_none());
+}
+
+#[test]
+fn test_running_lock_statistics() {
+    let mut env = setup();
+
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    for amount in [dec!("2"), dec!("6"), dec!("1")] {
+        lock_badges(&mut env, "lock_upgrade_badges", upgrade_resource, amount)
+            .expect_commit_success();
+    }
+
+    let stats = get_lock_status_v2(&mut env).upgrade_lock_stats;
+    assert_eq!(stats.lock_count, 3);
+    assert_eq!(stats.total_locked, dec!("9"));
+    assert_eq!(stats.largest_lock, dec!("6"));
+    assert_eq!(stats.average_lock, dec!("3"));
+}
+
+#[test]
+fn test_contribution_digest_matches_off_chain_computation() {
+    let mut env = setup();
+    let (first, first_badge) = create_contributor_account(&mut env);
+    let (second, second_badge) = create_contributor_account(&mut env);
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_badges_as(
+        &mut env,
+        "lock_admin_badges",
+        admin_resource,
+        dec!("3"),
+        &second_badge,
+    )
+    .expect_commit_success();
+    lock_badges_as(
+        &mut env,
+        "lock_upgrade_badges",
+        upgrade_resource,
+        dec!("2"),
+        &first_badge,
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "get_contribution_digest",
+            manifest_args!(),
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+    let digest: Hash = receipt.expect_commit_success().output(1);
+
+    let mut entries = vec![
+        (second, dec!("3"), dec!("0")),
+        (first, dec!("0"), dec!("2")),
+    ];
+    entries.sort_by_key(|(account, _, _)| account.as_node_id().0);
+    assert_eq!(digest, hash(scrypto_encode(&entries).unwrap()));
+}
+
+fn get_receipt_resource(env: &mut TestEnv) -> ResourceAddress {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "get_receipt_resource",
+            manifest_args!(),
+        )
+        .build();
+
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success()
+        .output(1)
+}
+
+#[test]
+fn test_attributed_lock_mints_soulbound_receipt() {
+    let mut env = setup();
+    let (contributor, owner_badge) = create_contributor_account(&mut env);
+    let receipt_resource = get_receipt_resource(&mut env);
+
+    let admin_resource = env.v1_admin_badge_resource;
+    lock_badges_as(
+        &mut env,
+        "lock_admin_badges",
+        admin_resource,
+        dec!("2"),
+        &owner_badge,
+    )
+    .expect_commit_success();
+
+    // Unattributed locks have nobody to mint a receipt to
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("1")).expect_commit_success();
+
+    assert_eq!(
+        env.ledger
+            .get_component_balance(contributor, receipt_resource),
+        dec!("1")
+    );
+    let unattributed_account = env.account.account_address;
+    assert_eq!(
+        env.ledger
+            .get_component_balance(unattributed_account, receipt_resource),
+        dec!("0")
+    );
+
+    // The receipt cannot be withdrawn, even by the account owner
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            env.account.account_address,
+            ACCOUNT_OWNER_BADGE,
+            [owner_badge],
+        )
+        .withdraw_from_account(contributor, receipt_resource, dec!("1"))
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+    receipt.expect_commit_failure();
+}
+
+fn get_relinquished_resource(env: &mut TestEnv) -> ResourceAddress {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "get_relinquished_resource",
+            manifest_args!(),
+        )
+        .build();
+
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success()
+        .output(1)
+}
+
+#[test]
+fn test_lock_methods_return_relinquished_tokens() {
+    let mut env = setup();
+    let relinquished_resource = get_relinquished_resource(&mut env);
+    let (beneficiary, _) = create_contributor_account(&mut env);
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("2")).expect_commit_success();
+    lock_badges(
+        &mut env,
+        "lock_upgrade_badges",
+        upgrade_resource,
+        dec!("0.5"),
+    )
+    .expect_commit_success();
+    lock_badges_for(
+        &mut env,
+        "lock_admin_badges_for",
+        admin_resource,
+        dec!("1"),
+        beneficiary,
+    )
+    .expect_commit_success();
+    lock_admin_badges_for_many(&mut env, dec!("3"), vec![(beneficiary, dec!("3"))])
+        .expect_commit_success();
+
+    // The tokens go to whoever supplied the badges, one per badge locked
+    let locker = env.account.account_address;
+    assert_eq!(
+        env.ledger
+            .get_component_balance(locker, relinquished_resource),
+        dec!("6.5")
+    );
+}
+
+// Burns `amount` RELINQUISHED tokens (or another resource) held by the test account
+fn burn_derivative(
+    env: &mut TestEnv,
+    resource: ResourceAddress,
+    amount: Decimal,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(env.account.account_address, resource, amount)
+        .take_all_from_worktop(resource, "tokens")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                "burn_derivative",
+                manifest_args!(lookup.bucket("tokens")),
+            )
+        })
+        .build();
+
+    env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    )
+}
+
+#[test]
+fn test_burn_derivative_leaves_lock_totals_untouched() {
+    let mut env = setup();
+    let relinquished_resource = get_relinquished_resource(&mut env);
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("2")).expect_commit_success();
+    lock_badges(&mut env, "lock_upgrade_badges", upgrade_resource, dec!("1"))
+        .expect_commit_success();
+    let status_before = get_lock_status(&mut env);
+
+    let receipt = burn_derivative(&mut env, relinquished_resource, dec!("3"));
+    let event: RelinquishedTokensBurnedEvent =
+        find_event(&receipt, "RelinquishedTokensBurnedEvent");
+    assert_eq!(event.amount, dec!("3"));
+
+    assert_eq!(
+        env.ledger
+            .get_component_balance(env.account.account_address, relinquished_resource),
+        Decimal::ZERO
+    );
+    let status_after = get_lock_status(&mut env);
+    assert_eq!(
+        status_after.admin_badges_locked,
+        status_before.admin_badges_locked
+    );
+    assert_eq!(
+        status_after.upgrade_badges_locked,
+        status_before.upgrade_badges_locked
+    );
+    assert_eq!(status_after.admin_badges_locked, dec!("2"));
+
+    // Only RELINQUISHED tokens are accepted
+    burn_derivative(&mut env, XRD, dec!("1")).expect_commit_failure();
+}
+
+// Claims the commemorative NFT of the account whose owner badge is held by the test account
+fn claim_commemorative_nft(
+    env: &mut TestEnv,
+    owner_badge: &NonFungibleLocalId,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            env.account.account_address,
+            ACCOUNT_OWNER_BADGE,
+            [owner_badge.clone()],
+        )
+        .pop_from_auth_zone("account_proof")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                "claim_commemorative_nft",
+                (lookup.proof("account_proof"),),
+            )
+        })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+
+    env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    )
+}
+
+#[test]
+fn test_commemorative_nft_can_be_claimed_once_by_contributors() {
+    let mut env = setup();
+    let (beneficiary, beneficiary_badge) = create_contributor_account(&mut env);
+    let (_, bystander_badge) = create_contributor_account(&mut env);
+
+    // Accounts that never contributed are not eligible
+    claim_commemorative_nft(&mut env, &bystander_badge).expect_commit_failure();
+
+    // Locks made by a custodian on the beneficiary's behalf make it eligible
+    let admin_resource = env.v1_admin_badge_resource;
+    lock_badges_for(
+        &mut env,
+        "lock_admin_badges_for",
+        admin_resource,
+        dec!("2"),
+        beneficiary,
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "get_commemorative_resource",
+            manifest_args!(),
+        )
+        .build();
+    let commemorative_resource: ResourceAddress = env
+        .ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success()
+        .output(1);
+
+    claim_commemorative_nft(&mut env, &beneficiary_badge).expect_commit_success();
+    let claimer = env.account.account_address;
+    assert_eq!(
+        env.ledger
+            .get_component_balance(claimer, commemorative_resource),
+        dec!("1")
+    );
+    let data: V1CommemorativeNft = env
+        .ledger
+        .get_non_fungible_data(commemorative_resource, beneficiary_badge.clone());
+    assert_eq!(data.contributor, beneficiary);
+
+    claim_commemorative_nft(&mut env, &beneficiary_badge).expect_commit_failure();
+}
+
+#[test]
+fn test_commemorative_nft_tier_is_upgraded_as_thresholds_are_crossed() {
+    let mut env = setup();
+    let (contributor, owner_badge) = create_contributor_account(&mut env);
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_badges_for(
+        &mut env,
+        "lock_admin_badges_for",
+        admin_resource,
+        dec!("0.5"),
+        contributor,
+    )
+    .expect_commit_success();
+    claim_commemorative_nft(&mut env, &owner_badge).expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "get_commemorative_resource",
+            manifest_args!(),
+        )
+        .build();
+    let commemorative_resource: ResourceAddress = env
+        .ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success()
+        .output(1);
+    let tier = |env: &mut TestEnv| {
+        env.ledger
+            .get_non_fungible_data::<V1CommemorativeNft>(
+                commemorative_resource,
+                owner_badge.clone(),
+            )
+            .tier
+    };
+
+    // Below the bronze threshold there is no tier yet
+    assert_eq!(tier(&mut env), None);
+
+    lock_badges_for(
+        &mut env,
+        "lock_upgrade_badges_for",
+        upgrade_resource,
+        dec!("5"),
+        contributor,
+    )
+    .expect_commit_success();
+    assert_eq!(tier(&mut env), Some(V1CommemorativeTier::Silver));
+
+    // Admin and upgrade badges count towards the same cumulative total
+    lock_badges_for(
+        &mut env,
+        "lock_admin_badges_for",
+        admin_resource,
+        dec!("4.5"),
+        contributor,
+    )
+    .expect_commit_success();
+    assert_eq!(tier(&mut env), Some(V1CommemorativeTier::Gold));
+}
+
+#[test]
+fn test_repeat_contributions_update_existing_receipt() {
+    let mut env = setup();
+    let (contributor, owner_badge) = create_contributor_account(&mut env);
+    let receipt_resource = get_receipt_resource(&mut env);
+
+    let admin_resource = env.v1_admin_badge_resource;
+    for amount in [dec!("2"), dec!("3")] {
+        lock_badges_as(
+            &mut env,
+            "lock_admin_badges",
+            admin_resource,
+            amount,
+            &owner_badge,
+        )
+        .expect_commit_success();
+    }
+
+    assert_eq!(
+        env.ledger
+            .get_component_balance(contributor, receipt_resource),
+        dec!("1")
+    );
+
+    // Admin receipts are keyed by the account address bytes followed by 0
+    let mut receipt_id = contributor.as_node_id().0.to_vec();
+    receipt_id.push(0);
+    let receipt: V1RelinquishmentReceipt = env.ledger.get_non_fungible_data(
+        receipt_resource,
+        NonFungibleLocalId::bytes(receipt_id).unwrap(),
+    );
+    assert_eq!(receipt.contributor, contributor);
+    assert_eq!(receipt.badge_type, V1BadgeType::Admin);
+    assert_eq!(receipt.total_locked, dec!("5"));
+
+    // The latest lock's details match its on-ledger record
+    assert_eq!(receipt.last_amount, dec!("3"));
+    let last_record = get_lock_record(&mut env, receipt.last_seq).unwrap();
+    assert_eq!(last_record.amount, receipt.last_amount);
+    assert_eq!(last_record.epoch, receipt.last_epoch);
+    assert_eq!(last_record.timestamp, receipt.last_contribution);
+}
+
+#[test]
+fn test_receipt_resource_uses_configured_branding() {
+    let mut env = setup();
+    let receipt_resource = get_receipt_resource(&mut env);
+
+    assert_eq!(
+        env.ledger.get_metadata(receipt_resource.into(), "name"),
+        Some(MetadataValue::String(
+            "V1 Relinquishment Receipt".to_string()
+        ))
+    );
+    assert_eq!(
+        env.ledger.get_metadata(receipt_resource.into(), "icon_url"),
+        Some(MetadataValue::Url(UncheckedUrl(
+            "https://radixnameservice.io/receipt.png".to_string()
+        )))
+    );
+}
+
+#[test]
+fn test_receipts_are_transferable_unless_soulbound() {
+    let mut env = setup_with_config(|config| V1LockerConfig {
+        soulbound_receipts: false,
+        ..config
+    });
+    let (contributor, owner_badge) = create_contributor_account(&mut env);
+    let receipt_resource = get_receipt_resource(&mut env);
+
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_badges_as(
+        &mut env,
+        "lock_upgrade_badges",
+        upgrade_resource,
+        dec!("1"),
+        &owner_badge,
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            env.account.account_address,
+            ACCOUNT_OWNER_BADGE,
+            [owner_badge],
+        )
+        .withdraw_from_account(contributor, receipt_resource, dec!("1"))
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success();
+
+    let receiver = env.account.account_address;
+    assert_eq!(
+        env.ledger.get_component_balance(receiver, receipt_resource),
+        dec!("1")
+    );
+}
+
+#[test]
+fn test_rejected_receipts_are_held_in_locker_for_claim() {
+    let mut env = setup();
+    let (contributor, owner_badge) = create_contributor_account(&mut env);
+    let receipt_resource = get_receipt_resource(&mut env);
+
+    // The contributor refuses unsolicited deposits
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            env.account.account_address,
+            ACCOUNT_OWNER_BADGE,
+            [owner_badge.clone()],
+        )
+        .call_method(
+            contributor,
+            "set_default_deposit_rule",
+            (DefaultDepositRule::Reject,),
+        )
+        .build();
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success();
+
+    // The lock still succeeds, with the receipt held in the locker
+    let admin_resource = env.v1_admin_badge_resource;
+    lock_badges_as(
+        &mut env,
+        "lock_admin_badges",
+        admin_resource,
+        dec!("1"),
+        &owner_badge,
+    )
+    .expect_commit_success();
+    assert_eq!(
+        env.ledger
+            .get_component_balance(contributor, receipt_resource),
+        dec!("0")
+    );
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "get_receipt_locker",
+            manifest_args!(),
+        )
+        .build();
+    let receipt_locker: ComponentAddress = env
+        .ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success()
+        .output(1);
+
+    // The contributor claims the held receipt into their own account
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            env.account.account_address,
+            ACCOUNT_OWNER_BADGE,
+            [owner_badge],
+        )
+        .call_method(
+            receipt_locker,
+            "claim",
+            (contributor, receipt_resource, dec!("1")),
+        )
+        .deposit_batch(contributor, ManifestExpression::EntireWorktop)
+        .build();
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success();
+
+    assert_eq!(
+        env.ledger
+            .get_component_balance(contributor, receipt_resource),
+        dec!("1")
+    );
+}
+
+fn mint_final_report(env: &mut TestEnv) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(env.component_address, "mint_final_report", manifest_args!())
+        .build();
+
+    env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    )
+}
+
+#[test]
+fn test_completion_mints_artifact_and_stops_receipts() {
+    // A preallocated account, so it can be configured before the ledger exists
+    let archive_key = Secp256k1PrivateKey::from_u64(1234).unwrap().public_key();
+    let archive_account = ComponentAddress::preallocated_account_from_public_key(&archive_key);
+    let mut env = setup_with_config(|config| V1LockerConfig {
+        completion_condition: Some(V1CompletionCondition {
+            admin_badges_target: dec!("3"),
+            upgrade_badges_target: dec!("2"),
+            archive_account,
+        }),
+        ..config
+    });
+    let (contributor, owner_badge) = create_contributor_account(&mut env);
+    let receipt_resource = get_receipt_resource(&mut env);
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_badges_as(
+        &mut env,
+        "lock_admin_badges",
+        admin_resource,
+        dec!("3"),
+        &owner_badge,
+    )
+    .expect_commit_success();
+    assert_eq!(get_lock_status_v2(&mut env).completed_at, None);
+    mint_final_report(&mut env).expect_commit_failure();
+
+    // This lock meets both targets; it still receives its receipt
+    let receipt = lock_badges_as(
+        &mut env,
+        "lock_upgrade_badges",
+        upgrade_resource,
+        dec!("2"),
+        &owner_badge,
+    );
+    let sealed: RelinquishmentSealedEvent = find_event(&receipt, "RelinquishmentSealedEvent");
+    assert_eq!(sealed.admin_badges_locked, dec!("3"));
+    assert_eq!(sealed.upgrade_badges_locked, dec!("2"));
+    assert_eq!(sealed.lock_count, 2);
+    assert_eq!(
+        env.ledger
+            .get_component_balance(contributor, receipt_resource),
+        dec!("2")
+    );
+
+    let status = get_lock_status_v2(&mut env);
+    assert!(status.completed_at.is_some());
+    let artifact_resource = status.fully_relinquished_artifact.unwrap();
+    assert_eq!(sealed.artifact_resource, Some(artifact_resource));
+    assert_eq!(status.completed_at, Some(sealed.sealed_at));
+    assert_eq!(
+        env.ledger
+            .get_component_balance(archive_account, artifact_resource),
+        dec!("1")
+    );
+
+    // The final report can be minted by anyone, exactly once
+    let history_root: Hash = query(&mut env, "get_contribution_digest");
+    let receipt = mint_final_report(&mut env);
+    let minted: FinalReportMintedEvent = find_event(&receipt, "FinalReportMintedEvent");
+    assert_eq!(minted.archive_account, archive_account);
+    assert_eq!(minted.history_root, history_root);
+    assert_eq!(
+        get_lock_status_v2(&mut env).final_report,
+        Some(minted.report_resource)
+    );
+    assert_eq!(
+        env.ledger
+            .get_component_balance(archive_account, minted.report_resource),
+        dec!("1")
+    );
+    mint_final_report(&mut env).expect_commit_failure();
+
+    // Locks are still accepted after completion, but no longer mint receipts
+    let (late_contributor, late_badge) = create_contributor_account(&mut env);
+    lock_badges_as(
+        &mut env,
+        "lock_admin_badges",
+        admin_resource,
+        dec!("1"),
+        &late_badge,
+    )
+    .expect_commit_success();
+    assert_eq!(
+        env.ledger
+            .get_component_balance(late_contributor, receipt_resource),
+        dec!("0")
+    );
+}
+
+fn verify_contributor(env: &mut TestEnv, account: ComponentAddress, min_amount: Decimal) -> bool {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "verify_contributor",
+            manifest_args!(account, min_amount),
+        )
+        .build();
+
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success()
+        .output(1)
+}
+
+#[test]
+fn test_verify_contributor() {
+    let mut env = setup();
+    let (contributor, _) = create_contributor_account(&mut env);
+
+    assert!(!verify_contributor(&mut env, contributor, dec!("0")));
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_badges_for(
+        &mut env,
+        "lock_admin_badges_for",
+        admin_resource,
+        dec!("2"),
+        contributor,
+    )
+    .expect_commit_success();
+    lock_badges_for(
+        &mut env,
+        "lock_upgrade_badges_for",
+        upgrade_resource,
+        dec!("1"),
+        contributor,
+    )
+    .expect_commit_success();
+
+    assert!(verify_contributor(&mut env, contributor, dec!("3")));
+    assert!(!verify_contributor(&mut env, contributor, dec!("3.5")));
+}
+
+// Decodes every application event with the given name emitted in a successful transaction
+fn find_events<T: ScryptoDecode>(receipt: &TransactionReceipt, name: &str) -> Vec<T> {
+    receipt
+        .expect_commit_success()
+        .application_events
+        .iter()
+        .filter(|(identifier, _)| identifier.1 == name)
+        .map(|(_, data)| scrypto_decode(data).unwrap())
+        .collect()
+}
+
+// Decodes the first application event with the given name emitted in a successful transaction
+fn find_event<T: ScryptoDecode>(receipt: &TransactionReceipt, name: &str) -> T {
+    find_events(receipt, name)
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| panic!("{} should be emitted", name))
+}
+
+#[test]
+fn test_lock_events_carry_resource_and_badge_type() {
+    let mut env = setup();
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    let receipt = lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("1"));
+    let event: V1AdminBadgesLockedEvent = find_event(&receipt, "V1AdminBadgesLockedEvent");
+    assert_eq!(event.resource_address, admin_resource);
+    assert_eq!(event.badge_type, V1BadgeType::Admin);
+
+    let receipt = lock_badges(&mut env, "lock_upgrade_badges", upgrade_resource, dec!("1"));
+    let event: V1UpgradeBadgeLockedEvent = find_event(&receipt, "V1UpgradeBadgeLockedEvent");
+    assert_eq!(event.resource_address, upgrade_resource);
+    assert_eq!(event.badge_type, V1BadgeType::Upgrade);
+}
+
+#[test]
+fn test_lock_events_identify_attributed_contributor() {
+    let mut env = setup();
+    let (contributor, owner_badge) = create_contributor_account(&mut env);
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let receipt = lock_badges_as(
+        &mut env,
+        "lock_admin_badges",
+        admin_resource,
+        dec!("1"),
+        &owner_badge,
+    );
+    let event: V1AdminBadgesLockedEvent = find_event(&receipt, "V1AdminBadgesLockedEvent");
+    assert_eq!(event.contributor, Some(contributor));
+
+    let receipt = lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("1"));
+    let event: V1AdminBadgesLockedEvent = find_event(&receipt, "V1AdminBadgesLockedEvent");
+    assert_eq!(event.contributor, None);
+}
+
+#[test]
+fn test_lock_events_are_sequenced_across_badge_types() {
+    let mut env = setup();
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    let receipt = lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("1"));
+    let first: V1AdminBadgesLockedEvent = find_event(&receipt, "V1AdminBadgesLockedEvent");
+    let receipt = lock_badges(&mut env, "lock_upgrade_badges", upgrade_resource, dec!("1"));
+    let second: V1UpgradeBadgeLockedEvent = find_event(&receipt, "V1UpgradeBadgeLockedEvent");
+
+    assert_eq!(first.sequence, 0);
+    assert_eq!(second.sequence, 1);
+}
+
+#[test]
+fn test_lock_events_carry_epoch() {
+    let mut env = setup();
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let receipt = lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("1"));
+    let event: V1AdminBadgesLockedEvent = find_event(&receipt, "V1AdminBadgesLockedEvent");
+
+    assert_eq!(event.epoch, env.ledger.get_current_epoch());
+}
+
+#[test]
+fn test_milestone_events_fire_once_per_milestone() {
+    let mut env = setup_with_config(|config| V1LockerConfig {
+        admin_milestones: vec![dec!("2"), dec!("5"), dec!("6")],
+        ..config
+    });
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let receipt = lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("1"));
+    assert!(find_events::<MilestoneReachedEvent>(&receipt, "MilestoneReachedEvent").is_empty());
+
+    let receipt = lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("1"));
+    let milestones: Vec<MilestoneReachedEvent> = find_events(&receipt, "MilestoneReachedEvent");
+    assert_eq!(milestones.len(), 1);
+    assert_eq!(milestones[0].milestone, dec!("2"));
+    assert_eq!(milestones[0].badge_type, V1BadgeType::Admin);
+
+    // A single lock can cross several milestones
+    let receipt = lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("5"));
+    let milestones: Vec<MilestoneReachedEvent> = find_events(&receipt, "MilestoneReachedEvent");
+    assert_eq!(
+        milestones
+            .iter()
+            .map(|event| event.milestone)
+            .collect::<Vec<_>>(),
+        vec![dec!("5"), dec!("6")]
+    );
+    assert_eq!(milestones[0].total_locked, dec!("7"));
+
+    let receipt = lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("1"));
+    assert!(find_events::<MilestoneReachedEvent>(&receipt, "MilestoneReachedEvent").is_empty());
+}
+
+#[test]
+fn test_new_contributor_event_on_first_attributed_lock() {
+    let mut env = setup();
+    let (first, first_badge) = create_contributor_account(&mut env);
+    let (second, _) = create_contributor_account(&mut env);
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let receipt = lock_badges_as(
+        &mut env,
+        "lock_admin_badges",
+        admin_resource,
+        dec!("1"),
+        &first_badge,
+    );
+    let event: NewContributorEvent = find_event(&receipt, "NewContributorEvent");
+    assert_eq!(event.contributor, first);
+    assert_eq!(event.contributor_number, 1);
+
+    // Repeat contributions are not announced again
+    let receipt = lock_badges_as(
+        &mut env,
+        "lock_admin_badges",
+        admin_resource,
+        dec!("1"),
+        &first_badge,
+    );
+    assert!(find_events::<NewContributorEvent>(&receipt, "NewContributorEvent").is_empty());
+
+    let receipt = lock_badges_for(
+        &mut env,
+        "lock_admin_badges_for",
+        admin_resource,
+        dec!("1"),
+        second,
+    );
+    let event: NewContributorEvent = find_event(&receipt, "NewContributorEvent");
+    assert_eq!(event.contributor, second);
+    assert_eq!(event.contributor_number, 2);
+}
+
+#[test]
+fn test_fully_relinquished_event_when_entire_supply_is_locked() {
+    let mut env = setup();
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    let receipt = lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("1000"));
+    assert!(
+        find_events::<V1FullyRelinquishedEvent>(&receipt, "V1FullyRelinquishedEvent").is_empty()
+    );
+
+    let receipt = lock_badges(
+        &mut env,
+        "lock_upgrade_badges",
+        upgrade_resource,
+        dec!("1000"),
+    );
+    let event: V1FullyRelinquishedEvent = find_event(&receipt, "V1FullyRelinquishedEvent");
+    assert_eq!(event.admin_badges_locked, dec!("1000"));
+    assert_eq!(event.upgrade_badges_locked, dec!("1000"));
+    assert_eq!(event.sequence, 1);
+    assert_eq!(
+        get_lock_status_v2(&mut env).entire_supply_locked_at,
+        Some(event.timestamp)
+    );
+}
+
+#[test]
+fn test_emit_status_snapshot() {
+    let mut env = setup();
+
+    let admin_resource = env.v1_admin_badge_resource;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("250")).expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "emit_status_snapshot",
+            manifest_args!(),
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+
+    let snapshot: StatusSnapshotEvent = find_event(&receipt, "StatusSnapshotEvent");
+    assert_eq!(snapshot.admin_badges_locked, dec!("250"));
+    assert_eq!(snapshot.admin_percent_locked, Some(dec!("25")));
+    assert_eq!(snapshot.upgrade_percent_locked, Some(dec!("0")));
+    assert_eq!(snapshot.lock_count, 1);
+    assert!(snapshot.admin_last_locked_at.is_some());
+    assert_eq!(snapshot.upgrade_last_locked_at, None);
+}
+
+// Like `lock_badges`, for the `try_lock_*` methods, depositing whatever they return
+fn try_lock_badges(
+    env: &mut TestEnv,
+    method: &str,
+    resource: ResourceAddress,
+    amount: Decimal,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(env.account.account_address, resource, amount)
+        .take_all_from_worktop(resource, "badges")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(env.component_address, method, (lookup.bucket("badges"),))
+        })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+
+    env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    )
+}
+
+#[test]
+fn test_try_lock_returns_rejected_bucket() {
+    let mut env = setup();
+    let relinquished_resource = get_relinquished_resource(&mut env);
+
+    // Upgrade badges offered as admin badges come back instead of aborting the transaction
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    let receipt = try_lock_badges(
+        &mut env,
+        "try_lock_admin_badges",
+        upgrade_resource,
+        dec!("2"),
+    );
+
+    let event: DepositRejectedEvent = find_event(&receipt, "DepositRejectedEvent");
+    assert_eq!(event.badge_type, V1BadgeType::Admin);
+    assert_eq!(event.resource_address, upgrade_resource);
+    assert_eq!(event.amount, dec!("2"));
+    assert_eq!(event.reason, V1RejectionReason::WrongResource);
+
+    let account = env.account.account_address;
+    assert_eq!(
+        env.ledger.get_component_balance(account, upgrade_resource),
+        dec!("1000")
+    );
+    assert_eq!(get_lock_status(&mut env).admin_badges_locked, dec!("0"));
+
+    // Matching badges are locked as usual
+    try_lock_badges(
+        &mut env,
+        "try_lock_upgrade_badges",
+        upgrade_resource,
+        dec!("2"),
+    )
+    .expect_commit_success();
+    assert_eq!(
+        env.ledger
+            .get_component_balance(account, relinquished_resource),
+        dec!("2")
+    );
+}
+
+fn check_supply_integrity(env: &mut TestEnv) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "check_supply_integrity",
+            manifest_args!(),
+        )
+        .build();
+
+    env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    )
+}
+
+#[test]
+fn test_supply_integrity_detects_minting() {
+    let mintable = FungibleResourceRoles {
+        mint_roles: mint_roles! {
+            minter => rule!(allow_all);
+            minter_updater => rule!(deny_all);
+        },
+        ..Default::default()
+    };
+    let mut env = setup_with(FungibleResourceRoles::default(), mintable, |config| config);
+
+    let receipt = check_supply_integrity(&mut env);
+    let intact: bool = receipt.expect_commit_success().output(1);
+    assert!(intact);
+    assert!(find_events::<SupplyAnomalyEvent>(&receipt, "SupplyAnomalyEvent").is_empty());
+    assert!(!get_lock_status_v2(&mut env).supply_anomaly_detected);
+
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .mint_fungible(upgrade_resource, dec!("10"))
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success();
+
+    let receipt = check_supply_integrity(&mut env);
+    let intact: bool = receipt.expect_commit_success().output(1);
+    assert!(!intact);
+    let anomalies: Vec<SupplyAnomalyEvent> = find_events(&receipt, "SupplyAnomalyEvent");
+    assert_eq!(anomalies.len(), 1);
+    assert_eq!(anomalies[0].resource_address, upgrade_resource);
+    assert_eq!(anomalies[0].supply_at_instantiation, Some(dec!("1000")));
+    assert_eq!(anomalies[0].current_supply, Some(dec!("1010")));
+    assert!(get_lock_status_v2(&mut env).supply_anomaly_detected);
+}
+
+fn targets_met(env: &mut TestEnv) -> (bool, bool) {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(env.component_address, "targets_met", manifest_args!())
+        .build();
+
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success()
+        .output(1)
+}
+
+#[test]
+fn test_targets_reached_and_met() {
+    let mut env = setup_with_config(|config| V1LockerConfig {
+        admin_target: Some(dec!("3")),
+        upgrade_target: Some(dec!("2")),
+        ..config
+    });
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    let receipt = lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("4"));
+    let event: TargetReachedEvent = find_event(&receipt, "TargetReachedEvent");
+    assert_eq!(event.badge_type, V1BadgeType::Admin);
+    assert_eq!(event.target, dec!("3"));
+    assert_eq!(event.total_locked, dec!("4"));
+    assert_eq!(targets_met(&mut env), (true, false));
+    assert!(!get_lock_status_v2(&mut env).targets_met);
+
+    // Each target is announced only the first time it is met
+    let receipt = lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("1"));
+    assert!(find_events::<TargetReachedEvent>(&receipt, "TargetReachedEvent").is_empty());
+
+    let receipt = lock_badges(&mut env, "lock_upgrade_badges", upgrade_resource, dec!("2"));
+    let event: TargetReachedEvent = find_event(&receipt, "TargetReachedEvent");
+    assert_eq!(event.badge_type, V1BadgeType::Upgrade);
+    assert_eq!(targets_met(&mut env), (true, true));
+    assert!(get_lock_status_v2(&mut env).targets_met);
+}
+
+#[test]
+fn test_checkpoint_events_every_interval() {
+    let mut env = setup_with_config(|config| V1LockerConfig {
+        admin_checkpoint_interval: Some(dec!("100")),
+        ..config
+    });
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let receipt = lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("60"));
+    assert!(find_events::<CheckpointEvent>(&receipt, "CheckpointEvent").is_empty());
+
+    let receipt = lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("40"));
+    let event: CheckpointEvent = find_event(&receipt, "CheckpointEvent");
+    assert_eq!(event.badge_type, V1BadgeType::Admin);
+    assert_eq!(event.checkpoint, dec!("100"));
+    assert_eq!(event.total_locked, dec!("100"));
+
+    // Crossing several checkpoints at once reports only the highest
+    let receipt = lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("250"));
+    let events: Vec<CheckpointEvent> = find_events(&receipt, "CheckpointEvent");
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].checkpoint, dec!("300"));
+    assert_eq!(events[0].total_locked, dec!("350"));
+
+    // Badge types without an interval have no checkpoints
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    let receipt = lock_badges(
+        &mut env,
+        "lock_upgrade_badges",
+        upgrade_resource,
+        dec!("500"),
+    );
+    assert!(find_events::<CheckpointEvent>(&receipt, "CheckpointEvent").is_empty());
+}
+
+#[test]
+fn test_progress_points_are_recorded_at_milestones_and_checkpoints() {
+    let mut env = setup_with_config(|config| V1LockerConfig {
+        admin_milestones: vec![dec!("50")],
+        upgrade_checkpoint_interval: Some(dec!("10")),
+        ..config
+    });
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("20")).expect_commit_success();
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("40")).expect_commit_success();
+    lock_badges(&mut env, "lock_upgrade_badges", upgrade_resource, dec!("5"))
+        .expect_commit_success();
+    lock_badges(
+        &mut env,
+        "lock_upgrade_badges",
+        upgrade_resource,
+        dec!("25"),
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "get_progress_points",
+            manifest_args!(0u64, 10u32),
+        )
+        .build();
+    let points: Vec<ProgressPoint> = env
+        .ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success()
+        .output(1);
+
+    assert_eq!(points.len(), 2);
+    assert_eq!(points[0].badge_type, V1BadgeType::Admin);
+    assert_eq!(points[0].total_locked, dec!("60"));
+    assert_eq!(points[0].sequence, 1);
+    assert_eq!(points[1].badge_type, V1BadgeType::Upgrade);
+    assert_eq!(points[1].total_locked, dec!("30"));
+    assert_eq!(points[1].sequence, 3);
+}
+
+#[test]
+fn test_campaign_auto_completes_when_targets_are_met() {
+    let mut env = setup_with_config(|config| V1LockerConfig {
+        admin_target: Some(dec!("3")),
+        upgrade_target: Some(dec!("2")),
+        ..config
+    });
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    let receipt = lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("3"));
+    assert!(
+        find_events::<RelinquishmentSealedEvent>(&receipt, "RelinquishmentSealedEvent").is_empty()
+    );
+
+    // Without a completion condition there is no archive account, so no artifact is minted
+    let receipt = lock_badges(&mut env, "lock_upgrade_badges", upgrade_resource, dec!("2"));
+    let sealed: RelinquishmentSealedEvent = find_event(&receipt, "RelinquishmentSealedEvent");
+    assert_eq!(sealed.artifact_resource, None);
+    assert_eq!(sealed.lock_count, 2);
+
+    let status = get_lock_status_v2(&mut env);
+    assert_eq!(status.completed_at, Some(sealed.sealed_at));
+    assert_eq!(status.fully_relinquished_artifact, None);
+}
+
+#[test]
+fn test_locks_within_a_minute_have_distinct_timestamps() {
+    let mut env = setup();
+
+    // Two locks 29 seconds apart within the same minute
+    let minute_start = (env.ledger.get_current_proposer_timestamp_ms() / 60_000 + 1) * 60_000;
+    let admin_resource = env.v1_admin_badge_resource;
+    env.ledger
+        .advance_to_round_at_timestamp(Round::of(2), minute_start + 1_000);
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("1")).expect_commit_success();
+    env.ledger
+        .advance_to_round_at_timestamp(Round::of(3), minute_start + 30_000);
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("1")).expect_commit_success();
+
+    let first = get_lock_record(&mut env, 0).unwrap().timestamp;
+    let second = get_lock_record(&mut env, 1).unwrap().timestamp;
+    assert_eq!(
+        second.seconds_since_unix_epoch - first.seconds_since_unix_epoch,
+        29
+    );
+}
+
+#[test]
+fn test_extended_status_reports_progress() {
+    let mut env = setup_with_config(|config| V1LockerConfig {
+        admin_target: Some(dec!("100")),
+        ..config
+    });
+
+    let admin_resource = env.v1_admin_badge_resource;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("50")).expect_commit_success();
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("50")).expect_commit_success();
+
+    let status = get_lock_status_v2(&mut env);
+    assert_eq!(status.admin_percent_locked, Some(dec!("10")));
+    assert_eq!(status.upgrade_percent_locked, Some(dec!("0")));
+    assert_eq!(status.lock_count, 2);
+    assert!(status.targets_met);
+}
+
+#[test]
+fn test_percent_of_supply_locked_uses_instantiation_snapshot() {
+    let mintable = FungibleResourceRoles {
+        mint_roles: mint_roles! {
+            minter => rule!(allow_all);
+            minter_updater => rule!(deny_all);
+        },
+        ..Default::default()
+    };
+    let mut env = setup_with(mintable, FungibleResourceRoles::default(), |config| config);
+
+    let admin_resource = env.v1_admin_badge_resource;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("500")).expect_commit_success();
+
+    // Minting after instantiation does not dilute the figure
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .mint_fungible(admin_resource, dec!("1000"))
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "percent_of_supply_locked",
+            manifest_args!(),
+        )
+        .build();
+    let percentages: (Option<Decimal>, Option<Decimal>) = env
+        .ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success()
+        .output(1);
+
+    assert_eq!(percentages, (Some(dec!("50")), Some(dec!("0"))));
+}
+
+#[test]
+fn test_lock_status_exposes_supply_snapshot() {
+    let mintable = FungibleResourceRoles {
+        mint_roles: mint_roles! {
+            minter => rule!(allow_all);
+            minter_updater => rule!(deny_all);
+        },
+        ..Default::default()
+    };
+    let mut env = setup_with(FungibleResourceRoles::default(), mintable, |config| config);
+
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .mint_fungible(upgrade_resource, dec!("1000"))
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success();
+    lock_badges(
+        &mut env,
+        "lock_upgrade_badges",
+        upgrade_resource,
+        dec!("250"),
+    )
+    .expect_commit_success();
+
+    // Progress is measured against the supply at instantiation, not the minted 2000
+    let status = get_lock_status_v2(&mut env);
+    assert_eq!(status.admin_supply_at_instantiation, Some(dec!("1000")));
+    assert_eq!(status.upgrade_supply_at_instantiation, Some(dec!("1000")));
+    assert_eq!(status.upgrade_percent_locked, Some(dec!("25")));
+}
+
+#[test]
+fn test_remaining_unlocked_supply() {
+    let mut env = setup();
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("250")).expect_commit_success();
+    lock_badges(
+        &mut env,
+        "lock_upgrade_badges",
+        upgrade_resource,
+        dec!("1000"),
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "remaining_unlocked_supply",
+            manifest_args!(),
+        )
+        .build();
+    let remaining: (Decimal, Decimal) = env
+        .ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success()
+        .output(1);
+
+    assert_eq!(remaining, (dec!("750"), dec!("0")));
+}
+
+// Calls a method taking no arguments and decodes its output
+fn query<T: ScryptoDecode>(env: &mut TestEnv, method: &str) -> T {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(env.component_address, method, manifest_args!())
+        .build();
+
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success()
+        .output(1)
+}
+
+// Calls a paginated method with `(start, limit)` and decodes its output
+fn query_page<T: ScryptoDecode>(env: &mut TestEnv, method: &str, start: u64, limit: u32) -> T {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(env.component_address, method, manifest_args!(start, limit))
+        .build();
+
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success()
+        .output(1)
+}
+
+#[test]
+fn test_is_fully_locked() {
+    let mut env = setup();
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("999")).expect_commit_success();
+    lock_badges(
+        &mut env,
+        "lock_upgrade_badges",
+        upgrade_resource,
+        dec!("1000"),
+    )
+    .expect_commit_success();
+
+    assert!(!query::<bool>(&mut env, "is_admin_fully_locked"));
+    assert!(query::<bool>(&mut env, "is_upgrade_fully_locked"));
+}
+
+#[test]
+fn test_amount_locked_of() {
+    let mut env = setup();
+
+    let admin_resource = env.v1_admin_badge_resource;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("7")).expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "amount_locked_of",
+            manifest_args!(admin_resource),
+        )
+        .call_method(
+            env.component_address,
+            "amount_locked_of",
+            manifest_args!(env.v1_upgrade_badge_resource),
+        )
+        .call_method(
+            env.component_address,
+            "amount_locked_of",
+            manifest_args!(XRD),
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+    let commit = receipt.expect_commit_success();
+
+    assert_eq!(commit.output::<Decimal>(1), dec!("7"));
+    assert_eq!(commit.output::<Decimal>(2), dec!("0"));
+    assert_eq!(commit.output::<Decimal>(3), dec!("0"));
+}
+
+#[test]
+fn test_get_all_locked_resources() {
+    let mut env = setup();
+
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_badges(&mut env, "lock_upgrade_badges", upgrade_resource, dec!("3"))
+        .expect_commit_success();
+
+    let resources: Vec<(ResourceAddress, Decimal)> = query(&mut env, "get_all_locked_resources");
+    assert_eq!(
+        resources,
+        vec![
+            (env.v1_admin_badge_resource, dec!("0")),
+            (upgrade_resource, dec!("3")),
+        ]
+    );
+}
+
+#[test]
+fn test_lock_other_v1_resource() {
+    let mut env = setup();
+
+    // Instantiate a second locker that also accepts a stray V1-era resource
+    let other_resource =
+        env.ledger
+            .create_fungible_resource(dec!("100"), 0, env.account.account_address);
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    let mut config = default_config(env.account.account_address);
+    config.other_v1_resources = vec![other_resource];
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            env.package_address,
+            "V1AuthRelinquishment",
+            "instantiate",
+            manifest_args!(admin_resource, upgrade_resource, config),
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+    env.component_address = receipt.expect_commit(true).new_component_addresses()[0];
+
+    let receipt = lock_other_v1_resource(&mut env, other_resource, dec!("4"));
+    let event: OtherV1ResourceLockedEvent = find_event(&receipt, "OtherV1ResourceLockedEvent");
+    assert_eq!(event.resource_address, other_resource);
+    assert_eq!(event.amount_locked, dec!("4"));
+    assert_eq!(event.total_locked_now, dec!("4"));
+
+    let resources: Vec<(ResourceAddress, Decimal)> = query(&mut env, "get_all_locked_resources");
+    assert_eq!(
+        resources,
+        vec![
+            (admin_resource, dec!("0")),
+            (upgrade_resource, dec!("0")),
+            (other_resource, dec!("4")),
+        ]
+    );
+
+    // The vault of the additional resource is reported alongside the badge vaults
+    let vault_ids: V1VaultIds = query_page(&mut env, "get_vault_ids", 0, 10);
+    assert_eq!(vault_ids.other_v1_vaults.len(), 1);
+    let (resource, other_vault) = vault_ids.other_v1_vaults[0];
+    assert_eq!(resource, other_resource);
+    assert_eq!(
+        env.ledger.inspect_vault_balance(*other_vault.as_node_id()),
+        Some(dec!("4"))
+    );
+
+    // Badge resources and resources that were not configured are rejected
+    lock_other_v1_resource(&mut env, admin_resource, dec!("1")).expect_commit_failure();
+    lock_other_v1_resource(&mut env, XRD, dec!("1")).expect_commit_failure();
+}
+
+fn lock_other_v1_resource(
+    env: &mut TestEnv,
+    resource: ResourceAddress,
+    amount: Decimal,
+) -> TransactionReceipt {
+    lock_with_confirmation(env, "lock_other_v1_resource", resource, amount)
+}
+
+// Withdraws `amount` of `resource` from the test account and passes it, with the
+// irreversibility confirmation, to a method taking a bucket and a confirmation
+fn lock_with_confirmation(
+    env: &mut TestEnv,
+    method: &str,
+    resource: ResourceAddress,
+    amount: Decimal,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(env.account.account_address, resource, amount)
+        .take_all_from_worktop(resource, "resources")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                method,
+                (lookup.bucket("resources"), IRREVERSIBILITY_CONFIRMATION),
+            )
+        })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+
+    env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    )
+}
+
+#[test]
+fn test_vault_ids_can_be_inspected() {
+    let mut env = setup();
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("12")).expect_commit_success();
+    lock_badges(&mut env, "lock_upgrade_badges", upgrade_resource, dec!("5"))
+        .expect_commit_success();
+    let (_, owner_badge) = create_contributor_account(&mut env);
+    let staged_lock_id = stage_lock(&mut env, admin_resource, dec!("3"), &owner_badge);
+
+    let vault_ids: V1VaultIds = query_page(&mut env, "get_vault_ids", 0, 10);
+    assert_eq!(
+        env.ledger
+            .inspect_vault_balance(*vault_ids.admin_vault.as_node_id()),
+        Some(dec!("12"))
+    );
+    assert_eq!(
+        env.ledger
+            .inspect_vault_balance(*vault_ids.upgrade_vault.as_node_id()),
+        Some(dec!("5"))
+    );
+    assert!(vault_ids.other_v1_vaults.is_empty());
+
+    // Badges awaiting their staging window sit in their own vault
+    assert_eq!(vault_ids.staged_lock_count, 1);
+    assert_eq!(vault_ids.staged_vaults.len(), 1);
+    let (id, staged_vault) = vault_ids.staged_vaults[0];
+    assert_eq!(id, staged_lock_id);
+    assert_eq!(
+        env.ledger.inspect_vault_balance(*staged_vault.as_node_id()),
+        Some(dec!("3"))
+    );
+
+    let vault_ids: V1VaultIds = query_page(&mut env, "get_vault_ids", 1, 10);
+    assert!(vault_ids.staged_vaults.is_empty());
+}
+
+#[test]
+fn test_component_info_reports_provenance() {
+    let mut env = setup();
+
+    let info: V1ComponentInfo = query(&mut env, "get_component_info");
+    assert_eq!(info.package_address, env.package_address);
+    assert_eq!(info.blueprint_name, "V1AuthRelinquishment");
+    assert_eq!(info.component_address, env.component_address);
+    assert_eq!(info.instantiated_epoch, env.ledger.get_current_epoch());
+    assert_eq!(info.admin_badge_resource, env.v1_admin_badge_resource);
+    assert_eq!(info.upgrade_badge_resource, env.v1_upgrade_badge_resource);
+
+    let relinquished_resource = get_relinquished_resource(&mut env);
+    assert_eq!(info.relinquished_resource, relinquished_resource);
+}
+
+#[test]
+fn test_describe_summarises_locked_badges() {
+    let mut env = setup();
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("500")).expect_commit_success();
+    lock_badges(
+        &mut env,
+        "lock_upgrade_badges",
+        upgrade_resource,
+        dec!("1000"),
+    )
+    .expect_commit_success();
+
+    let summary: String = query(&mut env, "describe");
+    assert_eq!(
+        summary,
+        "500/1,000 admin badges (50%) and 1,000/1,000 upgrade badges (100%) permanently locked"
+    );
+}
+
+#[test]
+fn test_status_json() {
+    let mut env = setup();
+
+    let admin_resource = env.v1_admin_badge_resource;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("250")).expect_commit_success();
+
+    let encoder = AddressBech32Encoder::new(&NetworkDefinition::simulator());
+    let admin_address = encoder
+        .encode(admin_resource.as_node_id().as_bytes())
+        .unwrap();
+    let upgrade_address = encoder
+        .encode(env.v1_upgrade_badge_resource.as_node_id().as_bytes())
+        .unwrap();
+
+    let json: String = query(&mut env, "get_status_json");
+    assert_eq!(
+        json,
+        format!(
+            concat!(
+                "{{\"admin_badges_locked\":\"250\",\"upgrade_badges_locked\":\"0\",",
+                "\"admin_badge_resource\":\"{}\",\"upgrade_badge_resource\":\"{}\",",
+                "\"admin_percent_locked\":\"25\",\"upgrade_percent_locked\":\"0\",",
+                "\"lock_count\":1,\"unique_contributors\":0,\"completed_at\":null,",
+                "\"entire_supply_locked_at\":null,\"targets_met\":false}}"
+            ),
+            admin_address, upgrade_address
+        )
+    );
+}
+
+#[test]
+fn test_get_locked_nft_ids() {
+    let mut env = setup_with_non_fungible_admin_badges();
+
+    let admin_resource = env.v1_admin_badge_resource;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("2")).expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "get_locked_nft_ids",
+            manifest_args!(V1BadgeType::Admin),
+        )
+        .call_method(
+            env.component_address,
+            "get_locked_nft_ids",
+            manifest_args!(V1BadgeType::Upgrade),
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+    let commit = receipt.expect_commit_success();
+
+    assert_eq!(commit.output::<Vec<NonFungibleLocalId>>(1).len(), 2);
+    assert!(commit.output::<Vec<NonFungibleLocalId>>(2).is_empty());
+}
+
+#[test]
+fn test_get_locked_nft_data() {
+    let mut env = setup_with_non_fungible_admin_badges();
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_non_fungibles_from_account(
+            env.account.account_address,
+            admin_resource,
+            [NonFungibleLocalId::integer(2)],
+        )
+        .take_all_from_worktop(admin_resource, "badges")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                "lock_admin_badges",
+                (
+                    lookup.bucket("badges"),
+                    None::<ManifestProof>,
+                    IRREVERSIBILITY_CONFIRMATION,
+                ),
+            )
+        })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "get_locked_nft_data",
+            manifest_args!(V1BadgeType::Admin, NonFungibleLocalId::integer(2)),
+        )
+        .call_method(
+            env.component_address,
+            "get_locked_nft_data",
+            manifest_args!(V1BadgeType::Admin, NonFungibleLocalId::integer(1)),
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+    let commit = receipt.expect_commit_success();
+
+    let data: Option<TestBadgeData> = commit.output(1);
+    assert_eq!(data.unwrap().name, "V1 Admin Badge #2");
+    // Badges still in circulation are not reported
+    assert!(commit.output::<Option<TestBadgeData>>(2).is_none());
+}
+
+#[test]
+fn test_contains_nft() {
+    let mut env = setup_with_non_fungible_admin_badges();
+
+    let admin_resource = env.v1_admin_badge_resource;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("3")).expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "contains_nft",
+            manifest_args!(V1BadgeType::Admin, NonFungibleLocalId::integer(1)),
+        )
+        .call_method(
+            env.component_address,
+            "contains_nft",
+            manifest_args!(V1BadgeType::Admin, NonFungibleLocalId::integer(4)),
+        )
+        .call_method(
+            env.component_address,
+            "contains_nft",
+            manifest_args!(V1BadgeType::Upgrade, NonFungibleLocalId::integer(1)),
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+    let commit = receipt.expect_commit_success();
+
+    assert!(commit.output::<bool>(1));
+    assert!(!commit.output::<bool>(2));
+    assert!(!commit.output::<bool>(3));
+}
+
+fn preview_lock(env: &mut TestEnv, badge_type: V1BadgeType, amount: Decimal) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "preview_lock",
+            manifest_args!(badge_type, amount),
+        )
+        .build();
+
+    env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    )
+}
+
+#[test]
+fn test_preview_lock_does_not_lock() {
+    let mut env = setup_with_config(|config| V1LockerConfig {
+        admin_target: Some(dec!("100")),
+        admin_milestones: vec![dec!("10"), dec!("50"), dec!("90"), dec!("200")],
+        ..config
+    });
+
+    let admin_resource = env.v1_admin_badge_resource;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("40")).expect_commit_success();
+
+    let result: V1LockPreview = preview_lock(&mut env, V1BadgeType::Admin, dec!("60"))
+        .expect_commit_success()
+        .output(1);
+    let preview = result.status;
+
+    // Only the milestones crossed by this lock are reported as reached
+    assert_eq!(result.milestones_reached, vec![dec!("50"), dec!("90")]);
+    assert_eq!(result.next_milestone, Some(dec!("200")));
+    assert_eq!(preview.admin_badges_locked, dec!("100"));
+    assert_eq!(preview.admin_percent_locked, Some(dec!("10")));
+    assert_eq!(preview.lock_count, 2);
+    assert_eq!(preview.admin_lock_stats.largest_lock, dec!("60"));
+    assert_eq!(preview.admin_lock_stats.average_lock, dec!("50"));
+    assert!(preview.targets_met);
+
+    // Nothing was locked
+    let status = get_lock_status_v2(&mut env);
+    assert_eq!(status.admin_badges_locked, dec!("40"));
+    assert!(!status.targets_met);
+
+    // A lock crossing no milestone reports none, and milestones of the other type are ignored
+    let result: V1LockPreview = preview_lock(&mut env, V1BadgeType::Upgrade, dec!("1"))
+        .expect_commit_success()
+        .output(1);
+    assert!(result.milestones_reached.is_empty());
+    assert_eq!(result.next_milestone, None);
+}
+
+#[test]
+fn test_preview_lock_is_refused_outside_the_campaign_window() {
+    let mut env = setup_with_config(|config| V1LockerConfig {
+        campaign_start_epoch: Some(Epoch::of(50)),
+        campaign_end_epoch: Some(Epoch::of(100)),
+        ..config
+    });
+
+    // Previews are refused whenever the lock itself would be
+    env.ledger.set_current_epoch(Epoch::of(40));
+    preview_lock(&mut env, V1BadgeType::Admin, dec!("1")).expect_commit_failure();
+
+    env.ledger.set_current_epoch(Epoch::of(50));
+    preview_lock(&mut env, V1BadgeType::Admin, dec!("1")).expect_commit_success();
+
+    env.ledger.set_current_epoch(Epoch::of(100));
+    preview_lock(&mut env, V1BadgeType::Admin, dec!("1")).expect_commit_failure();
+}
+
+#[test]
+fn test_status_reports_campaign_countdown() {
+    let mut env = setup_with_config(|config| V1LockerConfig {
+        campaign_end_epoch: Some(Epoch::of(100)),
+        ..config
+    });
+
+    env.ledger.set_current_epoch(Epoch::of(90));
+    let status = get_lock_status_v2(&mut env);
+    assert_eq!(status.campaign_end_epoch, Some(Epoch::of(100)));
+    assert_eq!(status.epochs_remaining, Some(10));
+    assert!(status.campaign_window_open);
+
+    env.ledger.set_current_epoch(Epoch::of(100));
+    let status = get_lock_status_v2(&mut env);
+    assert_eq!(status.epochs_remaining, Some(0));
+    assert!(!status.campaign_window_open);
+}
+
+#[test]
+fn test_locks_are_refused_outside_the_campaign_window() {
+    let mut env = setup_with_config(|config| V1LockerConfig {
+        campaign_start_epoch: Some(Epoch::of(50)),
+        campaign_end_epoch: Some(Epoch::of(100)),
+        ..config
+    });
+    let admin_resource = env.v1_admin_badge_resource;
+
+    env.ledger.set_current_epoch(Epoch::of(40));
+    assert!(!get_lock_status_v2(&mut env).campaign_window_open);
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("1")).expect_commit_failure();
+
+    env.ledger.set_current_epoch(Epoch::of(50));
+    let status = get_lock_status_v2(&mut env);
+    assert_eq!(status.campaign_start_epoch, Some(Epoch::of(50)));
+    assert!(status.campaign_window_open);
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("1")).expect_commit_success();
+
+    env.ledger.set_current_epoch(Epoch::of(100));
+    assert!(!get_lock_status_v2(&mut env).campaign_window_open);
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("1")).expect_commit_failure();
+    assert_eq!(get_lock_status_v2(&mut env).admin_badges_locked, dec!("1"));
+}
+
+#[test]
+fn test_late_locks_are_accepted_and_flagged_under_accept_late_policy() {
+    let mut env = setup_with_config(|config| V1LockerConfig {
+        campaign_end_epoch: Some(Epoch::of(100)),
+        late_lock_policy: V1LateLockPolicy::AcceptLate,
+        ..config
+    });
+    let admin_resource = env.v1_admin_badge_resource;
+
+    env.ledger.set_current_epoch(Epoch::of(90));
+    let receipt = lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("1"));
+    let event: V1AdminBadgesLockedEvent = find_event(&receipt, "V1AdminBadgesLockedEvent");
+    assert!(!event.late);
+
+    env.ledger.set_current_epoch(Epoch::of(100));
+    let receipt = lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("2"));
+    let event: V1AdminBadgesLockedEvent = find_event(&receipt, "V1AdminBadgesLockedEvent");
+    assert!(event.late);
+    assert!(!get_lock_record(&mut env, 0).unwrap().late);
+    assert!(get_lock_record(&mut env, 1).unwrap().late);
+    assert!(!get_lock_status_v2(&mut env).campaign_window_open);
+}
+
+#[test]
+fn test_lock_badges_routes_buckets_by_resource() {
+    let mut env = setup();
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(env.account.account_address, admin_resource, dec!("4"))
+        .withdraw_from_account(env.account.account_address, upgrade_resource, dec!("2"))
+        .take_all_from_worktop(upgrade_resource, "upgrade")
+        .take_all_from_worktop(admin_resource, "admin")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                "lock_badges",
+                manifest_args!(
+                    vec![lookup.bucket("upgrade"), lookup.bucket("admin")],
+                    IRREVERSIBILITY_CONFIRMATION
+                ),
+            )
+        })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success();
+
+    let status = get_lock_status(&mut env);
+    assert_eq!(status.admin_badges_locked, dec!("4"));
+    assert_eq!(status.upgrade_badges_locked, dec!("2"));
+
+    // Buckets of any other resource are rejected
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(env.account.account_address, XRD, dec!("1"))
+        .take_all_from_worktop(XRD, "xrd")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                "lock_badges",
+                manifest_args!(vec![lookup.bucket("xrd")], IRREVERSIBILITY_CONFIRMATION),
+            )
+        })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_failure();
+}
+
+#[test]
+fn test_lock_all_emits_combined_event() {
+    let mut env = setup();
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(env.account.account_address, admin_resource, dec!("3"))
+        .withdraw_from_account(env.account.account_address, upgrade_resource, dec!("2"))
+        .take_all_from_worktop(admin_resource, "admin")
+        .take_all_from_worktop(upgrade_resource, "upgrade")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                "lock_all",
+                manifest_args!(
+                    Some(lookup.bucket("admin")),
+                    Some(lookup.bucket("upgrade")),
+                    IRREVERSIBILITY_CONFIRMATION
+                ),
+            )
+        })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+    receipt.expect_commit_success();
+
+    let event: V1BadgesLockedEvent = find_event(&receipt, "V1BadgesLockedEvent");
+    assert_eq!(event.admin_badges_locked, dec!("3"));
+    assert_eq!(event.upgrade_badges_locked, dec!("2"));
+    assert_eq!(event.sequences, vec![0, 1]);
+    assert!(
+        find_events::<V1AdminBadgesLockedEvent>(&receipt, "V1AdminBadgesLockedEvent").is_empty()
+    );
+    assert!(
+        find_events::<V1UpgradeBadgeLockedEvent>(&receipt, "V1UpgradeBadgeLockedEvent").is_empty()
+    );
+
+    // A single bucket is locked like `lock_upgrade_badges`
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(env.account.account_address, upgrade_resource, dec!("1"))
+        .take_all_from_worktop(upgrade_resource, "upgrade")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                "lock_all",
+                manifest_args!(
+                    None::<ManifestBucket>,
+                    Some(lookup.bucket("upgrade")),
+                    IRREVERSIBILITY_CONFIRMATION
+                ),
+            )
+        })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+    receipt.expect_commit_success();
+
+    let event: V1UpgradeBadgeLockedEvent = find_event(&receipt, "V1UpgradeBadgeLockedEvent");
+    assert_eq!(event.total_locked_now, dec!("3"));
+}
+
+#[test]
+fn test_lock_with_memo() {
+    let mut env = setup();
+
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    let lock_with_memo = |env: &mut TestEnv, memo: String| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(env.account.account_address, upgrade_resource, dec!("1"))
+            .take_all_from_worktop(upgrade_resource, "badges")
+            .with_name_lookup(|builder, lookup| {
+                builder.call_method(
+                    env.component_address,
+                    "lock_upgrade_badges_with_memo",
+                    manifest_args!(lookup.bucket("badges"), memo, IRREVERSIBILITY_CONFIRMATION),
+                )
+            })
+            .deposit_batch(
+                env.account.account_address,
+                ManifestExpression::EntireWorktop,
+            )
+            .build();
+        env.ledger.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+    };
+
+    let receipt = lock_with_memo(&mut env, "For V2!".to_string());
+    receipt.expect_commit_success();
+    let event: V1UpgradeBadgeLockedEvent = find_event(&receipt, "V1UpgradeBadgeLockedEvent");
+    assert_eq!(event.memo, Some("For V2!".to_string()));
+    assert_eq!(
+        get_lock_record(&mut env, 0).unwrap().memo,
+        Some("For V2!".to_string())
+    );
+
+    lock_with_memo(&mut env, "x".repeat(MAX_MEMO_LENGTH + 1)).expect_commit_failure();
+}
+
+#[test]
+fn test_empty_buckets_are_rejected() {
+    let mut env = setup();
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .take_all_from_worktop(admin_resource, "badges")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                "lock_admin_badges",
+                (
+                    lookup.bucket("badges"),
+                    None::<ManifestProof>,
+                    IRREVERSIBILITY_CONFIRMATION,
+                ),
+            )
+        })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_failure();
+
+    // try_lock returns the empty bucket without emitting a lock event
+    let receipt = try_lock_badges(&mut env, "try_lock_admin_badges", admin_resource, dec!("0"));
+    receipt.expect_commit_success();
+    let rejection: DepositRejectedEvent = find_event(&receipt, "DepositRejectedEvent");
+    assert_eq!(rejection.reason, V1RejectionReason::EmptyBucket);
+    assert!(
+        find_events::<V1AdminBadgesLockedEvent>(&receipt, "V1AdminBadgesLockedEvent").is_empty()
+    );
+    assert_eq!(get_lock_status(&mut env).admin_badges_locked, dec!("0"));
+}
+
+#[test]
+fn test_lock_requires_irreversibility_confirmation() {
+    let mut env = setup();
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(env.account.account_address, admin_resource, dec!("1"))
+        .take_all_from_worktop(admin_resource, "badges")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                "lock_admin_badges",
+                (
+                    lookup.bucket("badges"),
+                    None::<ManifestProof>,
+                    "i understand",
+                ),
+            )
+        })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_failure();
+
+    assert_eq!(get_lock_status(&mut env).admin_badges_locked, dec!("0"));
+}
+
+// Stages `amount` of `resource` from the test account on behalf of the account whose owner
+// badge is `owner_badge`
+fn stage_lock(
+    env: &mut TestEnv,
+    resource: ResourceAddress,
+    amount: Decimal,
+    owner_badge: &NonFungibleLocalId,
+) -> u64 {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(env.account.account_address, resource, amount)
+        .take_all_from_worktop(resource, "badges")
+        .create_proof_from_account_of_non_fungibles(
+            env.account.account_address,
+            ACCOUNT_OWNER_BADGE,
+            [owner_badge.clone()],
+        )
+        .pop_from_auth_zone("account_proof")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                "stage_lock",
+                (
+                    lookup.bucket("badges"),
+                    lookup.proof("account_proof"),
+                    IRREVERSIBILITY_CONFIRMATION,
+                ),
+            )
+        })
+        .build();
+
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success()
+        .output(1)
+}
+
+// Calls `confirm_lock` or `reclaim_staged_lock` with a proof of `owner_badge`
+fn resolve_staged_lock(
+    env: &mut TestEnv,
+    method: &str,
+    staged_lock_id: u64,
+    owner_badge: &NonFungibleLocalId,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            env.account.account_address,
+            ACCOUNT_OWNER_BADGE,
+            [owner_badge.clone()],
+        )
+        .pop_from_auth_zone("account_proof")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                method,
+                (staged_lock_id, lookup.proof("account_proof")),
+            )
+        })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+
+    env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    )
+}
+
+fn get_staged_lock(env: &mut TestEnv, staged_lock_id: u64) -> Option<V1StagedLock> {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            env.component_address,
+            "get_staged_lock",
+            manifest_args!(staged_lock_id),
+        )
+        .build();
+
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success()
+        .output(1)
+}
+
+#[test]
+fn test_staged_locks_can_be_reclaimed_or_confirmed() {
+    let mut env = setup();
+    let (_, owner_badge) = create_contributor_account(&mut env);
+    let admin_resource = env.v1_admin_badge_resource;
+
+    let reclaimed = stage_lock(&mut env, admin_resource, dec!("3"), &owner_badge);
+    assert_eq!(
+        get_staged_lock(&mut env, reclaimed).unwrap().status,
+        V1StagedLockStatus::Pending
+    );
+    let receipt = resolve_staged_lock(&mut env, "reclaim_staged_lock", reclaimed, &owner_badge);
+    let event: StagedLockReclaimedEvent = find_event(&receipt, "StagedLockReclaimedEvent");
+    assert_eq!(event.amount, dec!("3"));
+    assert_eq!(
+        env.ledger
+            .get_component_balance(env.account.account_address, admin_resource),
+        dec!("1000")
+    );
+
+    // Resolved locks cannot be resolved again
+    resolve_staged_lock(&mut env, "confirm_lock", reclaimed, &owner_badge).expect_commit_failure();
+
+    let confirmed = stage_lock(&mut env, admin_resource, dec!("2"), &owner_badge);
+    resolve_staged_lock(&mut env, "confirm_lock", confirmed, &owner_badge).expect_commit_success();
+    assert_eq!(
+        get_staged_lock(&mut env, confirmed).unwrap().status,
+        V1StagedLockStatus::Locked
+    );
+    assert_eq!(get_lock_status(&mut env).admin_badges_locked, dec!("2"));
+}
+
+#[test]
+fn test_expired_staged_locks_are_finalized_by_anyone() {
+    let mut env = setup();
+    let (contributor, owner_badge) = create_contributor_account(&mut env);
+    let admin_resource = env.v1_admin_badge_resource;
+
+    let staged_at = env.ledger.get_current_proposer_timestamp_ms();
+    let staged_lock_id = stage_lock(&mut env, admin_resource, dec!("5"), &owner_badge);
+
+    let finalize = |env: &mut TestEnv| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                env.component_address,
+                "finalize_staged_lock",
+                manifest_args!(staged_lock_id),
+            )
+            .build();
+        env.ledger.execute_manifest(manifest, vec![])
+    };
+    finalize(&mut env).expect_commit_failure();
+
+    // The window has passed: the depositor can no longer reclaim
+    env.ledger
+        .advance_to_round_at_timestamp(Round::of(2), staged_at + 61 * 60_000);
+    resolve_staged_lock(
+        &mut env,
+        "reclaim_staged_lock",
+        staged_lock_id,
+        &owner_badge,
+    )
+    .expect_commit_failure();
+
+    finalize(&mut env).expect_commit_success();
+    assert_eq!(get_lock_status(&mut env).admin_badges_locked, dec!("5"));
+    let relinquished_resource = get_relinquished_resource(&mut env);
+    assert_eq!(
+        env.ledger
+            .get_component_balance(contributor, relinquished_resource),
+        dec!("5")
+    );
+}
+
+#[test]
+fn test_instantiate_and_lock() {
+    let mut env = setup();
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(env.account.account_address, admin_resource, dec!("10"))
+        .withdraw_from_account(env.account.account_address, upgrade_resource, dec!("5"))
+        .take_all_from_worktop(admin_resource, "admin")
+        .take_all_from_worktop(upgrade_resource, "upgrade")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_function(
+                env.package_address,
+                "V1AuthRelinquishment",
+                "instantiate_and_lock",
+                manifest_args!(
+                    admin_resource,
+                    upgrade_resource,
+                    default_config(env.account.account_address),
+                    Some(lookup.bucket("admin")),
+                    Some(lookup.bucket("upgrade")),
+                    IRREVERSIBILITY_CONFIRMATION
+                ),
+            )
+        })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+    let component_address = receipt.expect_commit(true).new_component_addresses()[0];
+
+    let instantiated: V1LockerInstantiatedEvent = find_event(&receipt, "V1LockerInstantiatedEvent");
+    assert_eq!(instantiated.component_address, component_address);
+    let locked: V1BadgesLockedEvent = find_event(&receipt, "V1BadgesLockedEvent");
+    assert_eq!(locked.admin_badges_locked, dec!("10"));
+    assert_eq!(locked.upgrade_badges_locked, dec!("5"));
+
+    env.component_address = component_address;
+    let status = get_lock_status(&mut env);
+    assert_eq!(status.admin_badges_locked, dec!("10"));
+    assert_eq!(status.upgrade_badges_locked, dec!("5"));
+    let relinquished_resource = get_relinquished_resource(&mut env);
+    assert_eq!(
+        env.ledger
+            .get_component_balance(env.account.account_address, relinquished_resource),
+        dec!("15")
+    );
+}
+
+// Instantiates a MultiResourceLocker for two new resources held by the test account
+fn instantiate_multi_resource_locker(
+    env: &mut TestEnv,
+) -> (ComponentAddress, ResourceAddress, ResourceAddress) {
+    let account_address = env.account.account_address;
+    let first_resource = env
+        .ledger
+        .create_fungible_resource(dec!("100"), 18, account_address);
+    let second_resource = env
+        .ledger
+        .create_fungible_resource(dec!("100"), 18, account_address);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            env.package_address,
+            "MultiResourceLocker",
+            "instantiate",
+            manifest_args!(
+                vec![first_resource, second_resource],
+                "Legacy Badge Locker".to_string(),
+                "Permanently locks legacy badges".to_string()
+            ),
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+    let component_address = receipt.expect_commit(true).new_component_addresses()[0];
+
+    (component_address, first_resource, second_resource)
+}
+
+#[test]
+fn test_multi_resource_locker_locks_each_accepted_resource() {
+    let mut env = setup();
+    let (locker, first_resource, second_resource) = instantiate_multi_resource_locker(&mut env);
+    env.component_address = locker;
+
+    let receipt = lock_with_confirmation(&mut env, "lock", first_resource, dec!("3"));
+    let event: ResourceLockedEvent = find_event(&receipt, "ResourceLockedEvent");
+    assert_eq!(event.resource_address, first_resource);
+    assert_eq!(event.total_locked_now, dec!("3"));
+    assert_eq!(event.sequence, 0);
+    lock_with_confirmation(&mut env, "lock", second_resource, dec!("5")).expect_commit_success();
+    lock_with_confirmation(&mut env, "lock", XRD, dec!("1")).expect_commit_failure();
+
+    let statuses: Vec<LockedResourceStatus> = query(&mut env, "get_status");
+    assert_eq!(statuses.len(), 2);
+    assert_eq!(statuses[0].resource_address, first_resource);
+    assert_eq!(statuses[0].amount_locked, dec!("3"));
+    assert_eq!(statuses[0].lock_count, 1);
+    assert_eq!(statuses[1].resource_address, second_resource);
+    assert_eq!(statuses[1].amount_locked, dec!("5"));
+}
+
+#[test]
+fn test_locker_factory_creates_and_registers_lockers() {
+    let mut env = setup();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            env.package_address,
+            "LockerFactory",
+            "instantiate",
+            manifest_args!(),
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+    let factory = receipt.expect_commit(true).new_component_addresses()[0];
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            factory,
+            "create_locker",
+            manifest_args!(
+                env.v1_admin_badge_resource,
+                env.v1_upgrade_badge_resource,
+                default_config(env.account.account_address)
+            ),
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+    let created: LockerCreatedEvent = find_event(&receipt, "LockerCreatedEvent");
+    assert_eq!(created.index, 0);
+    assert_eq!(created.admin_badge_resource, env.v1_admin_badge_resource);
+
+    // The registered locker is a working V1AuthRelinquishment component
+    env.component_address = factory;
+    assert_eq!(query::<u64>(&mut env, "get_locker_count"), 1);
+    env.component_address = created.component_address;
+    let admin_resource = env.v1_admin_badge_resource;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("2")).expect_commit_success();
+    assert_eq!(get_lock_status(&mut env).admin_badges_locked, dec!("2"));
+}
+
+// Registers `locker` with `registry`, recording the contributor account as its registrant
+fn register_locker(
+    env: &mut TestEnv,
+    registry: ComponentAddress,
+    locker: ComponentAddress,
+    owner_badge: &NonFungibleLocalId,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            env.account.account_address,
+            ACCOUNT_OWNER_BADGE,
+            [owner_badge.clone()],
+        )
+        .pop_from_auth_zone("account_proof")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                registry,
+                "register_locker",
+                (locker, lookup.proof("account_proof")),
+            )
+        })
+        .build();
+
+    env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    )
+}
+
+#[test]
+fn test_locker_registry_tracks_lockers_and_combines_status() {
+    let mut env = setup();
+    let first_locker = env.component_address;
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("2")).expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            env.package_address,
+            "V1AuthRelinquishment",
+            "instantiate",
+            manifest_args!(
+                admin_resource,
+                upgrade_resource,
+                default_config(env.account.account_address)
+            ),
+        )
+        .call_function(
+            env.package_address,
+            "V1AuthRelinquishment",
+            "instantiate",
+            manifest_args!(
+                upgrade_resource,
+                admin_resource,
+                default_config(env.account.account_address)
+            ),
+        )
+        .call_function(
+            env.package_address,
+            "LockerRegistry",
+            "instantiate",
+            manifest_args!(admin_resource, upgrade_resource),
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+    let new_components = receipt
+        .expect_commit(true)
+        .new_component_addresses()
+        .clone();
+    let (second_locker, swapped_locker, registry) =
+        (new_components[0], new_components[1], new_components[2]);
+    env.component_address = second_locker;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("3")).expect_commit_success();
+    lock_badges(&mut env, "lock_upgrade_badges", upgrade_resource, dec!("1"))
+        .expect_commit_success();
+
+    let (registrant, owner_badge) = create_contributor_account(&mut env);
+    let receipt = register_locker(&mut env, registry, first_locker, &owner_badge);
+    let registered: LockerRegisteredEvent = find_event(&receipt, "LockerRegisteredEvent");
+    assert_eq!(registered.index, 0);
+    assert_eq!(registered.registrant, registrant);
+    register_locker(&mut env, registry, second_locker, &owner_badge).expect_commit_success();
+
+    // A locker can only be registered once, and only if it accepts the registry's resources
+    register_locker(&mut env, registry, first_locker, &owner_badge).expect_commit_failure();
+    register_locker(&mut env, registry, swapped_locker, &owner_badge).expect_commit_failure();
+
+    env.component_address = registry;
+    let lockers = query_page::<Vec<RegisteredLocker>>(&mut env, "get_all_lockers", 0, 10);
+    assert_eq!(lockers.len(), 2);
+    assert_eq!(lockers[1].component_address, second_locker);
+    assert_eq!(lockers[1].registrant, registrant);
+
+    let combined = query_page::<CombinedLockStatus>(&mut env, "get_combined_status", 0, 10);
+    assert_eq!(combined.locker_count, 2);
+    assert_eq!(combined.lockers_counted, 2);
+    assert_eq!(combined.admin_badges_locked, dec!("5"));
+    assert_eq!(combined.upgrade_badges_locked, dec!("1"));
+
+    // Totals are summed per page
+    let combined = query_page::<CombinedLockStatus>(&mut env, "get_combined_status", 1, 10);
+    assert_eq!(combined.lockers_counted, 1);
+    assert_eq!(combined.admin_badges_locked, dec!("3"));
+}
+
+// Instantiates a V1BadgeBurner for the test badge resources and points the environment at it
+fn instantiate_badge_burner(env: &mut TestEnv) {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            env.package_address,
+            "V1BadgeBurner",
+            "instantiate",
+            manifest_args!(env.v1_admin_badge_resource, env.v1_upgrade_badge_resource),
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+    env.component_address = receipt.expect_commit(true).new_component_addresses()[0];
+}
+
+#[test]
+fn test_badge_burner_burns_badges_with_running_totals() {
+    let burnable = || FungibleResourceRoles {
+        burn_roles: burn_roles! {
+            burner => rule!(allow_all);
+            burner_updater => rule!(deny_all);
+        },
+        ..Default::default()
+    };
+    let mut env = setup_with(burnable(), burnable(), |config| config);
+    instantiate_badge_burner(&mut env);
+
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_with_confirmation(&mut env, "burn_admin_badges", admin_resource, dec!("3"))
+        .expect_commit_success();
+    let receipt =
+        lock_with_confirmation(&mut env, "burn_upgrade_badges", upgrade_resource, dec!("2"));
+    let event: V1BadgesBurnedEvent = find_event(&receipt, "V1BadgesBurnedEvent");
+    assert_eq!(event.badge_type, V1BadgeType::Upgrade);
+    assert_eq!(event.amount_burned, dec!("2"));
+    assert_eq!(event.admin_badges_burned, dec!("3"));
+    assert_eq!(event.upgrade_badges_burned, dec!("2"));
+
+    // Burned badges are gone rather than sitting in a vault
+    let status = query::<V1BurnStatus>(&mut env, "get_burn_status");
+    assert_eq!(status.admin_badges_burned, dec!("3"));
+    assert_eq!(status.upgrade_badges_burned, dec!("2"));
+    assert_eq!(
+        env.ledger
+            .get_component_balance(env.account.account_address, admin_resource),
+        dec!("997")
+    );
+    assert_eq!(
+        env.ledger
+            .get_component_balance(env.component_address, admin_resource),
+        Decimal::ZERO
+    );
+
+    // Wrong-type buckets are rejected
+    lock_with_confirmation(&mut env, "burn_admin_badges", upgrade_resource, dec!("1"))
+        .expect_commit_failure();
+}
+
+#[test]
+fn test_badge_burner_fails_for_non_burnable_badges() {
+    let mut env = setup();
+    instantiate_badge_burner(&mut env);
+
+    let admin_resource = env.v1_admin_badge_resource;
+    lock_with_confirmation(&mut env, "burn_admin_badges", admin_resource, dec!("1"))
+        .expect_commit_failure();
+}
+
+// Creates an attestation resource with non-fungibles #1# and #2# held by the test account and
+// returns the global id of #1#, the attestation
+fn create_attestation(env: &mut TestEnv) -> NonFungibleGlobalId {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_non_fungible_resource(
+            OwnerRole::None,
+            NonFungibleIdType::Integer,
+            true,
+            NonFungibleResourceRoles::default(),
+            metadata!(),
+            Some([1, 2].map(|id| {
+                (
+                    NonFungibleLocalId::integer(id),
+                    TestBadgeData {
+                        name: "RNS V2 Launch Attestation".to_string(),
+                    },
+                )
+            })),
+        )
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+
+    NonFungibleGlobalId::new(
+        receipt.expect_commit(true).new_resource_addresses()[0],
+        NonFungibleLocalId::integer(1),
+    )
+}
+
+// Creates an attestation non-fungible held by the test account, instantiates an
+// EscrowRelinquishment with a deadline `deadline_minutes` from now and points the environment at
+// it. Returns the attestation.
+fn instantiate_escrow(env: &mut TestEnv, deadline_minutes: i64) -> NonFungibleGlobalId {
+    let attestation = create_attestation(env);
+
+    let now_seconds = env.ledger.get_current_proposer_timestamp_ms() / 1_000;
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            env.package_address,
+            "EscrowRelinquishment",
+            "instantiate",
+            manifest_args!(
+                env.v1_admin_badge_resource,
+                env.v1_upgrade_badge_resource,
+                attestation.clone(),
+                Instant::new(now_seconds + deadline_minutes * 60)
+            ),
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+    env.component_address = receipt.expect_commit(true).new_component_addresses()[0];
+
+    attestation
+}
+
+// Places badges from the test account in escrow on behalf of the owner of `owner_badge`
+fn escrow_badges(
+    env: &mut TestEnv,
+    resource: ResourceAddress,
+    amount: Decimal,
+    owner_badge: &NonFungibleLocalId,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(env.account.account_address, resource, amount)
+        .take_all_from_worktop(resource, "badges")
+        .create_proof_from_account_of_non_fungibles(
+            env.account.account_address,
+            ACCOUNT_OWNER_BADGE,
+            [owner_badge.clone()],
+        )
+        .pop_from_auth_zone("account_proof")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                "escrow",
+                (
+                    lookup.bucket("badges"),
+                    lookup.proof("account_proof"),
+                    IRREVERSIBILITY_CONFIRMATION,
+                ),
+            )
+        })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+
+    env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    )
+}
+
+// Refunds escrowed badges of `badge_type` into the test account with a proof of `owner_badge`
+fn refund_escrow(
+    env: &mut TestEnv,
+    badge_type: V1BadgeType,
+    owner_badge: &NonFungibleLocalId,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            env.account.account_address,
+            ACCOUNT_OWNER_BADGE,
+            [owner_badge.clone()],
+        )
+        .pop_from_auth_zone("account_proof")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                "refund",
+                (badge_type, lookup.proof("account_proof")),
+            )
+        })
+        .deposit_batch(
+            env.account.account_address,
+            ManifestExpression::EntireWorktop,
+        )
+        .build();
+
+    env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    )
+}
+
+#[test]
+fn test_escrow_is_refundable_until_v2_launch_is_attested() {
+    let mut env = setup();
+    let attestation = instantiate_escrow(&mut env, 60);
+    let (contributor, owner_badge) = create_contributor_account(&mut env);
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+
+    escrow_badges(&mut env, admin_resource, dec!("5"), &owner_badge).expect_commit_success();
+    let receipt = escrow_badges(&mut env, upgrade_resource, dec!("2"), &owner_badge);
+    let escrowed: BadgesEscrowedEvent = find_event(&receipt, "BadgesEscrowedEvent");
+    assert_eq!(escrowed.depositor, contributor);
+    assert_eq!(escrowed.depositor_total, dec!("2"));
+
+    // Before the attestation, badges can be refunded
+    refund_escrow(&mut env, V1BadgeType::Upgrade, &owner_badge).expect_commit_success();
+    assert_eq!(
+        env.ledger
+            .get_component_balance(env.account.account_address, upgrade_resource),
+        dec!("1000")
+    );
+    refund_escrow(&mut env, V1BadgeType::Upgrade, &owner_badge).expect_commit_failure();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            env.account.account_address,
+            attestation.resource_address(),
+            [attestation.local_id().clone()],
+        )
+        .pop_from_auth_zone("attestation_proof")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                "confirm_v2_launch",
+                (lookup.proof("attestation_proof"),),
+            )
+        })
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+    let finalized: EscrowFinalizedEvent = find_event(&receipt, "EscrowFinalizedEvent");
+    assert_eq!(finalized.trigger, EscrowFinalizationTrigger::Attestation);
+    assert_eq!(finalized.admin_badges_locked, dec!("5"));
+
+    // Once attested, the escrow is a permanent lock
+    refund_escrow(&mut env, V1BadgeType::Admin, &owner_badge).expect_commit_failure();
+    escrow_badges(&mut env, admin_resource, dec!("1"), &owner_badge).expect_commit_failure();
+    let status = query::<EscrowStatus>(&mut env, "get_escrow_status");
+    assert!(status.is_permanent);
+    assert_eq!(status.admin_badges_held, dec!("5"));
+    assert_eq!(
+        status.finalized_by,
+        Some(EscrowFinalizationTrigger::Attestation)
+    );
+}
+
+#[test]
+fn test_escrow_becomes_permanent_after_deadline() {
+    let mut env = setup();
+    instantiate_escrow(&mut env, 60);
+    let (_, owner_badge) = create_contributor_account(&mut env);
+    let admin_resource = env.v1_admin_badge_resource;
+    escrow_badges(&mut env, admin_resource, dec!("3"), &owner_badge).expect_commit_success();
+
+    let finalize = |env: &mut TestEnv| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                env.component_address,
+                "finalize_after_deadline",
+                manifest_args!(),
+            )
+            .build();
+        env.ledger.execute_manifest(manifest, vec![])
+    };
+    finalize(&mut env).expect_commit_failure();
+
+    let now = env.ledger.get_current_proposer_timestamp_ms();
+    env.ledger
+        .advance_to_round_at_timestamp(Round::of(2), now + 61 * 60_000);
+    refund_escrow(&mut env, V1BadgeType::Admin, &owner_badge).expect_commit_failure();
+
+    let receipt = finalize(&mut env);
+    let finalized: EscrowFinalizedEvent = find_event(&receipt, "EscrowFinalizedEvent");
+    assert_eq!(finalized.trigger, EscrowFinalizationTrigger::Deadline);
+    assert_eq!(finalized.admin_badges_locked, dec!("3"));
+    finalize(&mut env).expect_commit_failure();
+}
+
+// Calls `finalize_with_attestation` with a proof of the non-fungible `id` from the test account
+fn finalize_with_attestation(env: &mut TestEnv, id: &NonFungibleGlobalId) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            env.account.account_address,
+            id.resource_address(),
+            [id.local_id().clone()],
+        )
+        .pop_from_auth_zone("attestation_proof")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                env.component_address,
+                "finalize_with_attestation",
+                (lookup.proof("attestation_proof"),),
+            )
+        })
+        .build();
+
+    env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    )
+}
+
+#[test]
+fn test_attestation_completes_the_campaign() {
+    let mut env = setup();
+    let attestation = create_attestation(&mut env);
+
+    // Instantiate a second locker completed by the attestation
+    let config = V1LockerConfig {
+        attestation: Some(attestation.clone()),
+        ..default_config(env.account.account_address)
+    };
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            env.package_address,
+            "V1AuthRelinquishment",
+            "instantiate",
+            manifest_args!(
+                env.v1_admin_badge_resource,
+                env.v1_upgrade_badge_resource,
+                config
+            ),
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+    env.component_address = receipt.expect_commit(true).new_component_addresses()[0];
+
+    let admin_resource = env.v1_admin_badge_resource;
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("4")).expect_commit_success();
+    assert_eq!(
+        get_lock_status_v2(&mut env).attestation,
+        Some(attestation.clone())
+    );
+
+    // Another non-fungible of the attestation resource is rejected
+    let other_attestation = NonFungibleGlobalId::new(
+        attestation.resource_address(),
+        NonFungibleLocalId::integer(2),
+    );
+    finalize_with_attestation(&mut env, &other_attestation).expect_commit_failure();
+    assert_eq!(get_lock_status_v2(&mut env).completed_at, None);
+
+    let receipt = finalize_with_attestation(&mut env, &attestation);
+    let sealed: RelinquishmentSealedEvent = find_event(&receipt, "RelinquishmentSealedEvent");
+    assert!(sealed.attested);
+    assert_eq!(sealed.admin_badges_locked, dec!("4"));
+    assert_eq!(sealed.artifact_resource, None);
+    let status = get_lock_status_v2(&mut env);
+    assert_eq!(status.completed_at, Some(sealed.sealed_at));
+
+    // Completion is final, and locks are still accepted afterwards
+    finalize_with_attestation(&mut env, &attestation).expect_commit_failure();
+    lock_badges(&mut env, "lock_admin_badges", admin_resource, dec!("1")).expect_commit_success();
+}
+
+#[test]
+fn test_finalize_with_attestation_requires_configured_attestation() {
+    let mut env = setup();
+    let attestation = create_attestation(&mut env);
+    finalize_with_attestation(&mut env, &attestation).expect_commit_failure();
+}
+
+// Registers a pledge with `registry` on behalf of the owner of `owner_badge`, backed by proofs
+// of the pledged badges from the test account (no proof is created for a zero amount)
+fn pledge(
+    env: &mut TestEnv,
+    registry: ComponentAddress,
+    admin_badges: Decimal,
+    upgrade_badges: Decimal,
+    owner_badge: &NonFungibleLocalId,
+) -> TransactionReceipt {
+    let mut builder = ManifestBuilder::new().lock_fee_from_faucet();
+    if admin_badges.is_positive() {
+        builder = builder
+            .create_proof_from_account_of_amount(
+                env.account.account_address,
+                env.v1_admin_badge_resource,
+                admin_badges,
+            )
+            .pop_from_auth_zone("admin_badges_proof");
+    }
+    if upgrade_badges.is_positive() {
+        builder = builder
+            .create_proof_from_account_of_amount(
+                env.account.account_address,
+                env.v1_upgrade_badge_resource,
+                upgrade_badges,
+            )
+            .pop_from_auth_zone("upgrade_badges_proof");
+    }
+    let manifest = builder
+        .create_proof_from_account_of_non_fungibles(
+            env.account.account_address,
+            ACCOUNT_OWNER_BADGE,
+            [owner_badge.clone()],
+        )
+        .pop_from_auth_zone("account_proof")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                registry,
+                "pledge",
+                (
+                    admin_badges
+                        .is_positive()
+                        .then(|| lookup.proof("admin_badges_proof")),
+                    upgrade_badges
+                        .is_positive()
+                        .then(|| lookup.proof("upgrade_badges_proof")),
+                    lookup.proof("account_proof"),
+                ),
+            )
+        })
+        .build();
+
+    env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    )
+}
+
+#[test]
+fn test_pledges_are_compared_with_attributed_locks() {
+    let mut env = setup();
+    let locker = env.component_address;
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            env.package_address,
+            "PledgeRegistry",
+            "instantiate",
+            manifest_args!(locker),
+        )
+        .build();
+    let receipt = env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(
+            &env.account.public_key,
+        )],
+    );
+    let registry = receipt.expect_commit(true).new_component_addresses()[0];
+
+    let (first, first_badge) = create_contributor_account(&mut env);
+    let (_, second_badge) = create_contributor_account(&mut env);
+    let receipt = pledge(&mut env, registry, dec!("4"), dec!("1"), &first_badge);
+    let pledged: PledgeRegisteredEvent = find_event(&receipt, "PledgeRegisteredEvent");
+    assert_eq!(pledged.pledger, first);
+    assert_eq!(pledged.admin_badges, dec!("4"));
+    pledge(&mut env, registry, dec!("10"), dec!("0"), &second_badge).expect_commit_success();
+    pledge(&mut env, registry, dec!("0"), dec!("0"), &second_badge).expect_commit_failure();
+
+    // Pledges are bounded by the badges the pledger can prove
+    pledge(&mut env, registry, dec!("2000"), dec!("0"), &second_badge).expect_commit_failure();
+
+    // Pledges are fulfilled through the locker's attributed lock methods
+    let admin_resource = env.v1_admin_badge_resource;
+    let upgrade_resource = env.v1_upgrade_badge_resource;
+    lock_badges_as(
+        &mut env,
+        "lock_admin_badges",
+        admin_resource,
+        dec!("5"),
+        &first_badge,
+    )
+    .expect_commit_success();
+    lock_badges_as(
+        &mut env,
+        "lock_admin_badges",
+        admin_resource,
+        dec!("3"),
+        &second_badge,
+    )
+    .expect_commit_success();
+
+    env.component_address = registry;
+    let report = query_page::<PledgeReport>(&mut env, "pledged_vs_fulfilled", 0, 10);
+    assert_eq!(report.pledger_count, 2);
+    assert_eq!(report.pledge_count, 2);
+    assert_eq!(report.admin_badges_pledged, dec!("14"));
+    assert_eq!(report.admin_badges_fulfilled, dec!("7"));
+    assert_eq!(report.upgrade_badges_fulfilled, dec!("0"));
+    assert_eq!(report.fulfilled_pledge_count, 0);
+
+    env.component_address = locker;
+    lock_badges_as(
+        &mut env,
+        "lock_upgrade_badges",
+        upgrade_resource,
+        dec!("1"),
+        &first_badge,
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(registry, "confirm_fulfillment", manifest_args!(first))
+        .build();
+    let receipt = env.ledger.execute_manifest(manifest, vec![]);
+    let fulfilled: PledgeFulfilledEvent = find_event(&receipt, "PledgeFulfilledEvent");
+    assert_eq!(fulfilled.pledger, first);
+
+    // Withdrawn pledges are left out of the report
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            env.account.account_address,
+            ACCOUNT_OWNER_BADGE,
+            [second_badge.clone()],
+        )
+        .pop_from_auth_zone("account_proof")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                registry,
+                "withdraw_pledge",
+                (lookup.proof("account_proof"),),
+            )
+        })
+        .build();
+    env.ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &env.account.public_key,
+            )],
+        )
+        .expect_commit_success();
+
+    env.component_address = registry;
+    let report = query_page::<PledgeReport>(&mut env, "pledged_vs_fulfilled", 0, 10);
+    assert_eq!(report.pledger_count, 2);
+    assert_eq!(report.pledge_count, 1);
+    assert_eq!(report.admin_badges_fulfilled, dec!("4"));
+    assert_eq!(report.upgrade_badges_fulfilled, dec!("1"));
+    assert_eq!(report.fulfilled_pledge_count, 1);
+}